@@ -20,7 +20,14 @@ mod utils;
 #[doc(inline)]
 pub use self::{
     lending_iterator::{
+        DoubleEndedLendingIterator,
+        ExactSizeLendingIterator,
+        ExtendLending,
+        FromLendingIterator,
+        FusedLendingIterator,
         LendingIterator,
+        Restartable,
+        consumers,
         constructors::{
             FromFn,
             from_fn,
@@ -125,6 +132,15 @@ pub use ::nou::Gat;
 #[apply(cfg_futures)]
 pub use self::lending_iterator::constructors::from_stream;
 
+#[doc(inline)]
+#[apply(cfg_alloc)]
+pub use self::lending_iterator::constructors::{
+    interleave,
+    interleave_shortest,
+    interleave_weighted,
+    kmerge_by,
+};
+
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
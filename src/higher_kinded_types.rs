@@ -404,6 +404,42 @@ type HKTRef<T : ?Sized> = HKT!(&T);
 pub
 type HKTRefMut<T : ?Sized> = HKT!(&mut T);
 
+/// [`HKT!`] combinator: <code>[HKT!]\(\(A, B\)\)</code>, for two
+/// <code>impl [HKT][trait@HKT]</code> types `A` and `B`.
+///
+/// ```rust
+/// # #[cfg(any())] macro_rules! ignore {
+/// type HKTPair<A, B> = HKT!(<'lt> => (Apply!(A<'lt>), Apply!(B<'lt>)));
+/// # }
+/// ```
+///
+/// This is, for instance, the item type (modulo [`CanonicalHKT`]) of
+/// [`.zip()`][crate::LendingIterator::zip()]:
+///
+/**  - ```rust
+    use ::lending_iterator::prelude::*;
+
+    let (mut a, mut b) = ([0_u8, 1, 2], [3_u8, 4, 5]);
+    let mut iter: Box<dyn LendingIteratorDyn<
+        Item = HKTPair<HKTRefMut<[u8; 2]>, HKTRefMut<[u8; 2]>>,
+    >> =
+        a.windows_mut::<2>()
+            .zip(b.windows_mut::<2>())
+            .dyn_boxed()
+    ;
+    while let Some(([a, _], [_, b])) = iter.next() {
+        ::core::mem::swap(a, b);
+    }
+    drop(iter);
+    assert_eq!(a, [4, 5, 2]);
+    assert_eq!(b, [3, 0, 1]);
+    ``` */
+#[allow(type_alias_bounds)]
+pub
+type HKTPair<A : ?Sized + HKT, B : ?Sized + HKT> =
+    HKT!(<'lt> => (Feed<'lt, A>, Feed<'lt, B>))
+;
+
 #[doc(inline)]
 pub
 use crate::lending_iterator::r#dyn::HKTItem;
@@ -3,6 +3,7 @@
 use {
     ::core::{
         marker::PhantomData,
+        num::NonZeroUsize,
         ops::Not,
     },
     ::never_say_never::{
@@ -20,6 +21,11 @@ use {
 };
 
 pub use self::{
+    collect::{
+        extend_from_lending,
+        ExtendLending,
+        FromLendingIterator,
+    },
     r#dyn::LendingIteratorDyn,
 };
 
@@ -27,6 +33,9 @@ pub use self::{
 pub
 mod adapters;
 
+pub
+mod consumers;
+
 /// Functions, extension traits and types allowing direct construction of
 /// [`LendingIterator`]s (no need for custom types or implementations!).
 #[path = "constructors/_mod.rs"]
@@ -38,6 +47,8 @@ use r#dyn::*;
 pub(in crate)
 mod r#dyn;
 
+mod collect;
+
 mod impls;
 
 macro_rules! with_cfg_better_docs {( $($rules:tt)* ) => (
@@ -117,6 +128,32 @@ type Item<'lt, I : LendingIterator> =
 ///         That being said, `while let Some(item) = iter.next() {` works just
 ///         as well, to be honest.
 ///
+///       - Nor will there be a `.peekable()` adapter for lent items: the
+///         peeked item would have to be stored right next to the iterator it
+///         borrows from, that is, within a self-referential `struct`, which
+///         is not expressible without `unsafe` code (and this crate is
+///         `#![forbid(unsafe_code)]`). Hence [`.peekable()`][Self::peekable()]
+///         being restricted to items which do not borrow from the iterator.
+///
+///         The same applies to any adapter needing to hold onto an item in
+///         between calls to `.next()`, such as a `.group_by()` (which has to
+///         consume the first item of the next group to detect the end of the
+///         current one), or a sorted [`.merge_by()`][Self::merge_by()] /
+///         [`kmerge_by()`][crate::kmerge_by()] (which has to keep the head of
+///         each non-yielded source around, so as to compare it against the
+///         next heads), hence the latter two also being restricted to items
+///         which do not borrow from the iterators. The items of _different_
+///         iterators may coëxist, but only within a single `.next()` call.
+///
+///         Lookahead can instead be achieved by having the iterator lend
+///         the current item _and_ a view of what follows (_e.g._,
+///         [`windows_mut()`][constructors::windows_mut()] with a bigger
+///         window, or a [`from_fn()`][constructors::from_fn()] state which
+///         keeps its own lookahead), or, when the goal is to conditionally
+///         consume items, through [`.skip_while()`][Self::skip_while()],
+///         [`.take_while()`][Self::take_while()] or
+///         [`.find()`][Self::find()].
+///
 ///   - but the dual / other side of that API restriction is that it is way
 ///     simpler / less constraining, _for implementors_, to implement this
 ///     trait.
@@ -238,6 +275,11 @@ where
         ;
     )?
 
+    /// Whether `Self` is known to be a [`FusedLendingIterator`], so that
+    /// [`Fuse`] can skip its own bookkeeping (poor man's specialization).
+    #[doc(hidden)] /** Not part of the public API */
+    const ඞIS_FUSED: bool = false;
+
     /// Query the `next()` `Item` of this `Self` iterator.
     ///
     /// [`LendingIterator`] counterpart of [`Iterator::next()`].
@@ -246,6 +288,17 @@ where
     ) -> Option<Item<'_, Self>>
     ;
 
+    /// [`LendingIterator`] counterpart of [`Iterator::size_hint()`].
+    ///
+    /// Returns the `(lower, upper)` bounds on the remaining length of the
+    /// iterator, with the same semantics (and caveats) as for [`Iterator`]s.
+    fn size_hint (
+        self: &'_ Self,
+    ) -> (usize, Option<usize>)
+    {
+        (0, None)
+    }
+
     /// [`LendingIterator`] counterpart of [`Iterator::filter()`].
     fn filter<F> (
         self: Self,
@@ -343,261 +396,1704 @@ where
         self
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::count()`].
-    fn count<> (self: Self)
-      -> usize
-    where
-        Self : Sized,
-    {
-        self.fold(0_usize, |acc, _| acc + 1)
-    }
+    /// [`LendingIterator`] counterpart of [`Iterator::chain()`].
+    ///
+    /// The `other` iterator is required to lend the very same items as `Self`,
+    /// modulo [`CanonicalHKT`] "lifting" (_e.g._, a
+    /// [`from_iter()`][crate::from_iter()] of `u8`s can be chained with a
+    /// [`from_fn()`][crate::from_fn()] lending [`HKT!`]`(u8)`s, and an
+    /// `impl` [`LendingIterator`] can be chained with a
+    /// <code>dyn [LendingIteratorDyn]\<Item = [CanonicalHKT]\<…\>\></code>).
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
 
-    /// [`LendingIterator`] counterpart of [`Iterator::find()`].
-    fn find<'find> (
-        self: &'find mut Self,
-        mut predicate: impl 'find + FnMut(&Item<'_, Self>) -> bool,
-    ) -> Option<Item<'find, Self>>
+        let (mut a, mut b) = ([1, 2, 3], [10, 20, 30]);
+        let mut iter = a.windows_mut::<2>().chain(b.windows_mut::<2>());
+        while let Some(&mut [x, ref mut y]) = iter.next() {
+            *y += x;
+        }
+        assert_eq!(a, [1, 3, 6]);
+        assert_eq!(b, [10, 30, 60]);
+        ``` */
+    fn chain<J> (
+        self: Self,
+        other: J,
+    ) -> Chain<Self, J>
     where
         Self : Sized,
+        J : LendingIterator,
+        J : for<'any> LendingIteratorඞItem<'any, T = Item<'any, Self>>,
     {
-        use ::polonius_the_crab::prelude::*;
-        let mut this = self;
-        polonius_loop!(|this| -> Option<Item<'polonius, Self>> {
-            let ret = this.next();
-            if matches!(ret, Some(ref it) if predicate(it).not()) {
-                polonius_continue!();
-            }
-            polonius_return!(ret);
-        })
+        Chain { first: Some(self), second: Some(other) }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::fuse()`].
-    fn fuse (self: Self)
-      -> Fuse<Self>
+    /// [`LendingIterator`] counterpart of [`Iterator::zip()`]: iterates over
+    /// both iterators in lock-step, lending a pair of their respective items.
+    ///
+    /// The item HKT of the resulting iterator is thus, [modulo
+    /// canonicalization][CanonicalHKT],
+    /// <code>[HKTPair]\<[HKTItem]\<Self\>, [HKTItem]\<J\>\></code>.
+    ///
+    /// It stops as soon as either iterator is exhausted: see
+    /// [`.zip_longest()`][Self::zip_longest()] to keep going until both are.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut state = [1, 1, 2, 3, 5];
+        let mut scratch = [0; 5];
+        let mut iter =
+            state.windows_mut::<2>()
+                .zip(scratch.windows_mut::<2>())
+        ;
+        while let Some((&mut [a, b], &mut [ref mut lo, ref mut hi])) = iter.next() {
+            *lo += a;
+            *hi += b;
+        }
+        assert_eq!(scratch, [1, 2, 4, 6, 5]);
+        ``` */
+    fn zip<J : LendingIterator> (
+        self: Self,
+        other: J,
+    ) -> Zip<Self, J>
     where
         Self : Sized,
     {
-        Fuse(Some(self))
+        Zip { first: self, second: other }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::nth()`].
-    fn nth (
-        self: &'_ mut Self,
-        n: usize,
-    ) -> Option<Item<'_, Self>>
-    {
-        if let Some(n_minus_one) = n.checked_sub(1) {
-            self.skip(n_minus_one);
-        }
-        self.next()
-    }
+    /// Like [`.zip()`][Self::zip()], but for iterators of unequal length: it
+    /// keeps going until _both_ iterators are exhausted, lending an
+    /// [`EitherOrBoth`] of their items.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::{
+            lending_iterator::adapters::EitherOrBoth,
+            prelude::*,
+        };
 
-    /// [`LendingIterator`] counterpart of [`Iterator::position()`].
-    fn position<F> (
-        self: &'_ mut Self,
-        mut predicate: impl FnMut(Item<'_, Self>) -> bool,
-    ) -> Option<usize>
+        let mut long = [0, 1, 2, 3];
+        let mut short = [10, 20, 30];
+        let mut iter =
+            long.windows_mut::<2>()
+                .zip_longest(short.windows_mut::<2>())
+        ;
+        while let Some(items) = iter.next() {
+            match items {
+                | EitherOrBoth::Both([a, _], [_, b]) => ::core::mem::swap(a, b),
+                | EitherOrBoth::Left([_, b]) => *b = 42,
+                | EitherOrBoth::Right(_) => unreachable!(),
+            }
+        }
+        assert_eq!(long, [20, 30, 2, 42]);
+        assert_eq!(short, [10, 0, 1]);
+        ``` */
+    fn zip_longest<J : LendingIterator> (
+        self: Self,
+        other: J,
+    ) -> ZipLongest<Self, J>
     where
         Self : Sized,
     {
-        match
-            self.try_fold(0, |i, item| if predicate(item) {
-                Err(i)
-            } else {
-                Ok(i + 1)
-            })
-        {
-            | Err(position) => Some(position),
-            | Ok(_) => None,
+        ZipLongest {
+            first: self.fuse(),
+            second: other.fuse(),
         }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::skip()`].
-    fn skip (
+    /// Like [`.zip()`][Self::zip()], but for pairing each lent item with a
+    /// value from an ordinary [`Iterator`] (which comes first in the pair).
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [0; 4];
+        let mut iter = array.windows_mut::<2>().zip_iter("abc".chars());
+        while let Some((c, [_, next])) = iter.next() {
+            *next = c as u8;
+        }
+        assert_eq!(array, [0, b'a', b'b', b'c']);
+        ``` */
+    fn zip_iter<J : IntoIterator> (
         self: Self,
-        count: usize,
-    ) -> Skip<Self>
+        values: J,
+    ) -> ZipIter<Self, J::IntoIter>
     where
         Self : Sized,
     {
-        Skip {
-            iter: self,
-            to_skip: count.try_into().ok(),
-        }
+        ZipIter { iter: self, values: values.into_iter() }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::skip_while()`].
-    #[cfg(TODO)]
-    fn skip_while<F> (
-        self: Self,
-        predicate: F,
-    ) -> SkipWhile<Self, F>
+    /// [`LendingIterator`] counterpart of [`Iterator::enumerate()`].
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [0; 4];
+        let mut iter = array.windows_mut::<2>().enumerate();
+        while let Some((i, [_, next])) = iter.next() {
+            *next = i + 1;
+        }
+        assert_eq!(array, [0, 1, 2, 3]);
+        ``` */
+    fn enumerate (self: Self)
+      -> Enumerate<Self>
     where
-        F : FnMut(Item<'_, Self>) -> bool,
         Self : Sized,
     {
-        SkipWhile { iter: self, predicate }
+        Enumerate { iter: self, count: 0 }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::take()`].
-    fn take (
+    /// Lends nested [`LendingIterator`]s over (up to) `chunk_size` consecutive
+    /// items of `self`.
+    ///
+    /// Each [`Chunk`] mutably borrows the [`Chunks`] iterator, and whatever is
+    /// left of it when the latter is advanced gets skipped.
+    ///
+    /// Since the lent items cannot be stored, there is no way to know whether
+    /// there is a next chunk without consuming its first item, so this
+    /// requires `self` to be an [`ExactSizeLendingIterator`], whose
+    /// [`.len()`][ExactSizeLendingIterator::len()] tells as much. This way, no
+    /// empty chunk is ever yielded.
+    ///
+    /// Note that, for the same reason, there is no `.group_by()` adapter:
+    /// detecting the end of a group requires consuming the first item of the
+    /// next one (see `.peekable()` in the [trait docs][LendingIterator]).
+    ///
+    /// ## Panics
+    ///
+    /// If `chunk_size` is `0`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [0; 8];
+        let mut chunks = array.windows_mut::<2>().chunks(3);
+        let mut chunk_idx = 0;
+        while let Some(mut chunk) = chunks.next() {
+            chunk_idx += 1;
+            // Only process the first two windows of each chunk.
+            for _ in 0 .. 2 {
+                if let Some([_, next]) = chunk.next() {
+                    *next = chunk_idx;
+                }
+            }
+        }
+        assert_eq!(array, [0, 1, 1, 0, 2, 2, 0, 3]);
+        ``` */
+    fn chunks (
         self: Self,
-        count: usize,
-    ) -> Take<Self>
+        chunk_size: usize,
+    ) -> Chunks<Self>
     where
-        Self : Sized,
+        Self : Sized + ExactSizeLendingIterator,
     {
-        Take {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        Chunks {
             iter: self,
-            remaining: count,
+            chunk_size,
+            remaining: 0,
         }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::take_while()`].
-    #[cfg(TODO)]
-    fn take_while<F> (
+    /// Skips the items whose key, as computed by the `key` closure, is equal to
+    /// the one of the previously yielded item.
+    ///
+    /// Since the lent items cannot be kept around, it is their (owned) keys
+    /// which get compared.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 1, 2, 2, 2, 3, 1];
+        let mut iter =
+            array.windows_mut::<2>()
+                .dedup_by_key(|&&mut [a, _]| a)
+        ;
+        let mut firsts = vec![];
+        while let Some(&mut [a, _]) = iter.next() {
+            firsts.push(a);
+        }
+        assert_eq!(firsts, [1, 2, 3]);
+        ``` */
+    fn dedup_by_key<K : PartialEq, F> (
         self: Self,
-        f: F,
-    ) -> TakeWhile<Self, F>
+        key: F,
+    ) -> DedupByKey<Self, F, K>
     where
-        F : FnMut(&Self::Item) -> bool,
         Self : Sized,
+        F : FnMut(&'_ Item<'_, Self>) -> K,
     {
-        TakeWhile(self)
+        DedupByKey { iter: self, key, last_key: None }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::map()`].
+    /// Skips the items whose key, as computed by the `key` closure, has
+    /// already been seen.
     ///
-    ///   - **Turbofishing the `NewItemType` is mandatory**, otherwise you'll
-    ///     run into issues with non-higher-order closures.
+    /// Since the lent items cannot be kept around, it is their (owned) keys
+    /// which get stored, within a [`BTreeSet`][::alloc::collections::BTreeSet].
     ///
-    ///     See the module-level documentation of [`crate::higher_kinded_types`]
-    ///     for more info.
+    /// ## Example
     ///
-    ///     But the TL,DR is that you'd use it as:
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 1, 3, 2, 4];
+        let mut iter =
+            array.windows_mut::<2>()
+                .unique_by_key(|&&mut [a, _]| a)
+        ;
+        let mut count = 0;
+        while let Some(_) = iter.next() {
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        ``` */
+    #[apply(cfg_alloc)]
+    fn unique_by_key<K : Ord, F> (
+        self: Self,
+        key: F,
+    ) -> UniqueByKey<Self, F, K>
+    where
+        Self : Sized,
+        F : FnMut(&'_ Item<'_, Self>) -> K,
+    {
+        UniqueByKey { iter: self, key, seen: <_>::default() }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::step_by()`].
     ///
-    ///     <code>lending_iter.map::\<[HKT!]\(ReturnType\<\'_\>\), _>\(</code>
+    /// Each step is performed through a single call to
+    /// [`.nth()`][Self::nth()], so it is as cheap as the latter (_e.g._,
+    /// _O(1)_ for [`windows_mut()`][constructors::windows_mut()]).
     ///
-    ///   - the second idiosyncracy is that, for technical reasons[^1] related
-    ///     to the maximally generic aspect of this API, the closure itself
-    ///     cannot just be a `Self::Item<'_> -> Feed<'_, NewItemType>` closure,
-    ///     and instead, requires that an extra `[]` dummy parameter be part
-    ///     of the signature:
+    /// ## Panics
     ///
-    ///     ```rust
-    ///     # #[cfg(any)] macro_rules! ignore { /*
-    ///     lending_iter.map::<HKT…, _>(|[], item| { … })
-    ///                                  👆
-    ///     # */ }
-    ///     ```
+    /// If `step` is `0`.
     ///
-    /// [^1]: In the case where `Self::Item<'_>` does _not_ depend on `'_`, the
-    /// return type then technically can't depend on it either, so Rust
-    /// complains about this (in a rather obtuse fashion). We solve this by
-    /// requiring that extra `[]` parameter which acts as a convenient-to-write
-    /// `PhantomData` which does depend on `'_`.
-    fn map<NewItemType : HKT, F> (
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [0; 7];
+        let mut iter = array.windows_mut::<2>().step_by(2);
+        while let Some([a, b]) = iter.next() {
+            *a += 1;
+            *b += 1;
+        }
+        assert_eq!(array, [1, 1, 1, 1, 1, 1, 0]);
+        ``` */
+    fn step_by (
         self: Self,
-        f: F,
-    ) -> Map<Self, F, NewItemType>
+        step: usize,
+    ) -> StepBy<Self>
     where
-        for<'next>
-            F : FnMut(
-                [&'next Self; 0],
-                Item<'next, Self>,
-            ) -> A!(NewItemType<'next>)
-        ,
         Self : Sized,
     {
-        Map { iter: self, map: f, _phantom_ty: <_>::default() }
+        assert!(step != 0, "step must be non-zero");
+        StepBy {
+            iter: self,
+            step_minus_one: step - 1,
+            first_take: true,
+        }
     }
 
-    pervasive_hkt_choices! {
-        (map, Map)(
-            /// Convenience method: same as [`.map()`][Self::map()], but for
-            /// hard-coding the `HKT` parameter to
-            /// <code>[HKTRef]\<R\> = [HKT!]\(\&R\)</code>.
-            ///
-            /// This alleviates the call-sites (no more turbofishing needed!)
-            /// for such pervasive use cases 🙂
-            map_to_ref: [R : ?Sized], HKTRef<R>, -> &'any R,
-            /// Convenience method: same as [`.map()`][Self::map()], but for
-            /// hard-coding the `HKT` parameter to
-            /// <code>[HKTRefMut]\<R\> = [HKT!]\(\&mut R\)</code>.
-            ///
-            /// This alleviates the call-sites (no more turbofishing needed!)
-            /// for such pervasive use cases 🙂
-            map_to_mut: [R : ?Sized], HKTRefMut<R>, -> &'any mut R,
-        ),
+    /// [`LendingIterator`] counterpart of [`Iterator::inspect()`].
+    fn inspect<F> (
+        self: Self,
+        f: F,
+    ) -> Inspect<Self, F>
+    where
+        Self : Sized,
+        F : FnMut(&'_ Item<'_, Self>),
+    {
+        Inspect { iter: self, f }
     }
 
-    /// Convenience shorthand for
-    /// <code>[.map…\(…\)][Self::map()][.into_iter()][Self::into_iter()]</code>.
+    /// Like [`.inspect()`][Self::inspect()], but with `&mut` access to each
+    /// item before it is yielded.
     ///
-    /// When the return type of the `.map()` closure is not lending
-    /// / borrowing from `*self`, it becomes possible to call
-    /// [.into_iter()][Self::into_iter()] on it right away.
-    ///
-    /// Moreover, it makes the `[], ` closure arg hack no longer necessary.
+    /// ## Example
     ///
-    /// This convenience function encompasses both things, thence returning
-    /// an [`Iterator`] (not a [`LendingIterator`]!).
-    fn map_into_iter<F, NonLendingItem> (
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [-1_i32, 2, -3, 4];
+        let mut iter =
+            array.windows_mut::<1>()
+                .inspect_mut(|[x]| *x = x.abs())
+                .filter(|&&mut [x]| x > 2)
+        ;
+        while let Some([x]) = iter.next() {
+            *x *= 10;
+        }
+        assert_eq!(array, [1, 2, 30, 40]);
+        ``` */
+    fn inspect_mut<F> (
         self: Self,
         f: F,
-    ) -> MapIntoIter<Self, F>
+    ) -> InspectMut<Self, F>
     where
-        F : FnMut(Item<'_, Self>) -> NonLendingItem,
         Self : Sized,
+        F : FnMut(&'_ mut Item<'_, Self>),
     {
-        MapIntoIter(self, f)
+        InspectMut { iter: self, f }
     }
 
-    /// [`LendingIterator`] counterpart of [`Iterator::filter_map()`].
+    /// Repeats `self` endlessly, by calling `reset` on it whenever it gets
+    /// exhausted.
     ///
-    /// All the caveats and remarks of [`.map()`][Self::map()] apply, go check
-    /// them up.
-    fn filter_map<NewItemType : HKT, F> (
+    /// [`Iterator::cycle()`] requires `Clone`, which most lending iterators
+    /// cannot provide (_e.g._, a [`windows_mut()`][constructors::windows_mut()]
+    /// iterator, which holds a `&mut` slice). Instead, it is up to `reset` to
+    /// restart the iterator in place.
+    ///
+    /// If the iterator is still empty after having been `reset`, `None` is
+    /// returned.
+    ///
+    /// See also [`.cycle()`][Self::cycle()], for [`Restartable`] iterators.
+    fn cycle_with<R> (
         self: Self,
-        f: F,
-    ) -> FilterMap<Self, F, NewItemType>
+        reset: R,
+    ) -> CycleWith<Self, R>
     where
-        for<'next>
-            F : FnMut(
-                [&'next Self; 0],
-                Item<'next, Self>,
-            ) -> Option<A!(NewItemType<'next>)>
-        ,
         Self : Sized,
+        R : FnMut(&'_ mut Self),
     {
-        FilterMap { iter: self, map: f, _phantom_ty: <_>::default() }
+        CycleWith { iter: self, reset }
     }
 
-    pervasive_hkt_choices! {
-        (filter_map, FilterMap)(
-            /// Convenience method: same as
-            /// [`.filter_map()`][Self::filter_map()], but for hard-coding the
-            /// `HKT` parameter to <code>[HKTRef]\<R\> = [HKT!]\(\&R\)</code>.
-            ///
-            /// All the caveats and remarks of
-            /// [`.map_to_ref()`][Self::map_to_ref()] apply, go check them up.
-            filter_map_to_ref: [R : ?Sized], HKTRef<R>, -> Option<&'any R>,
+    /// [`LendingIterator`] counterpart of [`Iterator::cycle()`], for
+    /// [`Restartable`] iterators (rather than `Clone` ones).
+    ///
+    /// Shorthand for <code>[.cycle_with][Self::cycle_with()]\([Restartable::restart]\)</code>.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
 
-            /// Convenience method: same as
-            /// [`.filter_map()`][Self::filter_map()], but for hard-coding the
-            /// `HKT` parameter to <code>[HKTRefMut]\<R\> = [HKT!]\(\&mut R\)</code>.
-            ///
-            /// All the caveats and remarks of
-            /// [`.map_to_mut()`][Self::map_to_mut()] apply, go check them up.
-            filter_map_to_mut: [R : ?Sized], HKTRefMut<R>, -> Option<&'any mut R>,
-        ),
+        let mut array = [1.0, 0.0, 0.0, 0.0, 0.0];
+        // Multi-pass relaxation, sweeping the buffer three times.
+        let mut iter = array.windows_mut::<2>().cycle().take(3 * 4);
+        while let Some(&mut [prev, ref mut next]) = iter.next() {
+            *next = (*next + prev) / 2.0;
+        }
+        assert_eq!(array, [1.0, 0.875, 0.6875, 0.5, 0.34375]);
+        ``` */
+    fn cycle (self: Self)
+      -> Cycle<Self>
+    where
+        Self : Sized + Restartable,
+    {
+        self.cycle_with(Self::restart)
     }
 
-    /// Convenience shorthand for
-    /// <code>[.filter_map…\(…\)][Self::filter_map()][.into_iter()][Self::into_iter()]</code>.
+    /// [`LendingIterator`] counterpart of `Iterator::intersperse_with()`:
+    /// lends the items of `self` ([`EitherOrBoth::Left`]) with separators,
+    /// obtained by calling `separator`, in between ([`EitherOrBoth::Right`]).
     ///
-    /// When the return type of the `.filter_map()` closure is not lending
-    /// / borrowing from `*self`, it becomes possible to call
+    /// Yielding a separator requires knowing whether there is a next item,
+    /// which, for a [`LendingIterator`], cannot be found out by consuming
+    /// (and thus holding onto) that next item (see `.peekable()` in the
+    /// [trait docs][LendingIterator]). So it is `self`'s
+    /// [`.size_hint()`][Self::size_hint()] lower bound which is relied upon,
+    /// to yield the separators on their own, as [`Iterator`]s do (_e.g._, for
+    /// an [`ExactSizeLendingIterator`]). When it cannot tell, the next item
+    /// is consumed, and yielded along with the separator which precedes it,
+    /// as an [`EitherOrBoth::Both`]`(item, separator)`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::{
+            lending_iterator::adapters::EitherOrBoth,
+            prelude::*,
+        };
+
+        #[apply(Gat!)]
+        fn render<'sep, I> (mut fragments: I)
+          -> String
+        where
+            I : for<'n> LendingIterator<
+                Item<'n> = EitherOrBoth<&'n str, &'sep str>,
+            >,
+        {
+            let mut rendered = String::new();
+            while let Some(item) = fragments.next() {
+                match item {
+                    | EitherOrBoth::Left(fragment) => rendered += fragment,
+                    | EitherOrBoth::Right(separator) => rendered += separator,
+                    | EitherOrBoth::Both(fragment, separator) => {
+                        rendered += separator;
+                        rendered += fragment;
+                    },
+                }
+            }
+            rendered
+        }
+
+        // The `size_hint()` of `from_fn()` cannot tell whether there is a next
+        // fragment, so each separator is lent along with the next fragment.
+        let mut buffer = String::new();
+        let mut n = 0;
+        let fragments =
+            lending_iterator::from_fn::<HKT!(&str), _, _>(&mut buffer, |buffer| {
+                if n == 3 { return None; }
+                n += 1;
+                buffer.clear();
+                buffer.extend(::core::iter::repeat('x').take(n));
+                Some(buffer)
+            })
+        ;
+        assert_eq!(render(fragments.intersperse_with(|| ", ")), "x, xx, xxx");
+
+        // Whereas with an exact `size_hint()`, separators are lent on their own.
+        let mut fragments =
+            lending_iterator::from_iter(["x", "xx", "xxx"])
+                .intersperse_with(|| ", ")
+        ;
+        assert_eq!(fragments.len(), 5);
+        assert_eq!(fragments.next(), Some(EitherOrBoth::Left("x")));
+        assert_eq!(fragments.next(), Some(EitherOrBoth::Right(", ")));
+        assert_eq!(fragments.next(), Some(EitherOrBoth::Left("xx")));
+        ``` */
+    fn intersperse_with<Sep, F> (
+        self: Self,
+        separator: F,
+    ) -> IntersperseWith<Self, F, Sep>
+    where
+        Self : Sized,
+        F : FnMut() -> Sep,
+    {
+        IntersperseWith {
+            iter: self,
+            separator,
+            needs_separator: false,
+            _phantom_ty: <_>::default(),
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::cloned()`]: converts
+    /// `self` into an [`Iterator`] (not a [`LendingIterator`]!) of clones of
+    /// the `&T` / `&mut T` (or any other <code>impl [Deref]\<Target = T\></code>)
+    /// lent items.
+    ///
+    /// [Deref]: ::core::ops::Deref
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut n = 0;
+        let lines =
+            lending_iterator::from_fn::<HKT!(&String), _, _>(String::new(), |line| {
+                if n == 3 { return None; }
+                n += 1;
+                line.push('x');
+                Some(line)
+            })
+            .cloned()
+            .collect::<Vec<String>>()
+        ;
+        assert_eq!(lines, ["x", "xx", "xxx"]);
+        ``` */
+    fn cloned<T> (self: Self)
+      -> Cloned<Self, T>
+    where
+        Self : Sized,
+        for<'any>
+            Item<'any, Self> : ::core::ops::Deref<Target = T>
+        ,
+        T : Clone,
+    {
+        Cloned { iter: self, _phantom_ty: <_>::default() }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::copied()`]: converts
+    /// `self` into an [`Iterator`] (not a [`LendingIterator`]!) of copies of
+    /// the `&T` / `&mut T` (or any other <code>impl [Deref]\<Target = T\></code>)
+    /// lent items.
+    ///
+    /// [Deref]: ::core::ops::Deref
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [0, 1, 2, 3];
+        let windows =
+            array.windows_mut::<3>()
+                .copied()
+                .collect::<Vec<[u8; 3]>>()
+        ;
+        assert_eq!(windows, [[0, 1, 2], [1, 2, 3]]);
+        ``` */
+    fn copied<T> (self: Self)
+      -> Copied<Self, T>
+    where
+        Self : Sized,
+        for<'any>
+            Item<'any, Self> : ::core::ops::Deref<Target = T>
+        ,
+        T : Copy,
+    {
+        Copied { iter: self, _phantom_ty: <_>::default() }
+    }
+
+    /// Like [`.cloned()`][Self::cloned()], but based on [`ToOwned`] rather
+    /// than [`Clone`], so as to support lent `&[T]` or `&str` items, for
+    /// instance.
+    ///
+    /// [`ToOwned`]: ::alloc::borrow::ToOwned
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [0, 1, 2, 3];
+        let windows: Vec<Vec<u8>> =
+            array.windows_mut::<3>()
+                .map_to_ref(|[], window| &window[1 ..])
+                .to_owned_items()
+                .collect()
+        ;
+        assert_eq!(windows, [[1, 2], [2, 3]]);
+        ``` */
+    #[apply(cfg_alloc)]
+    fn to_owned_items<T> (self: Self)
+      -> ToOwnedItems<Self, T>
+    where
+        Self : Sized,
+        for<'any>
+            Item<'any, Self> : ::core::ops::Deref<Target = T>
+        ,
+        T : ?Sized + ::alloc::borrow::ToOwned,
+    {
+        ToOwnedItems { iter: self, _phantom_ty: <_>::default() }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::collect()`], for the
+    /// collections implementing [`FromLendingIterator`] for these items.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut buffer = String::new();
+        let mut n = 0;
+        let s: String =
+            lending_iterator::from_fn::<HKT!(&str), _, _>(&mut buffer, |buffer| {
+                if n == 3 { return None; }
+                n += 1;
+                buffer.clear();
+                buffer.extend(::core::iter::repeat('x').take(n));
+                buffer.push(' ');
+                Some(buffer)
+            })
+            .collect_into()
+        ;
+        assert_eq!(s, "x xx xxx ");
+        ``` */
+    fn collect_into<C> (self: Self)
+      -> C
+    where
+        Self : Sized,
+        C : FromLendingIterator<HKTItem<Self>>,
+    {
+        FromLendingIterator::<HKTItem<Self>>::from_lending_iter(self)
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::count()`].
+    fn count<> (self: Self)
+      -> usize
+    where
+        Self : Sized,
+    {
+        self.fold(0_usize, |acc, _| acc + 1)
+    }
+
+    /// Sums the owned projections of the items, obtained through `f`.
+    ///
+    /// [`LendingIterator`] counterpart of
+    /// [`.map(f).sum()`][Iterator::sum()], built on top of
+    /// [`.try_fold()`][LendingIterator::try_fold()].
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 4];
+        let sum: i32 =
+            array
+                .windows_mut::<2>()
+                .sum_by(|&mut [a, b]| a * b)
+        ;
+        assert_eq!(sum, 1 * 2 + 2 * 3 + 3 * 4);
+        ``` */
+    fn sum_by<S> (
+        self: Self,
+        f: impl FnMut(Item<'_, Self>) -> S,
+    ) -> S
+    where
+        Self : Sized,
+        S : ::core::iter::Sum,
+    {
+        consumers::accumulate(self, consumers::Summed::new(), f)
+    }
+
+    /// Multiplies the owned projections of the items, obtained through `f`.
+    ///
+    /// [`LendingIterator`] counterpart of
+    /// [`.map(f).product()`][Iterator::product()], built on top of
+    /// [`.try_fold()`][LendingIterator::try_fold()].
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 4];
+        let product: i32 =
+            array
+                .windows_mut::<2>()
+                .product_by(|&mut [a, b]| b - a + 1)
+        ;
+        assert_eq!(product, 8);
+        ``` */
+    fn product_by<P> (
+        self: Self,
+        f: impl FnMut(Item<'_, Self>) -> P,
+    ) -> P
+    where
+        Self : Sized,
+        P : ::core::iter::Product,
+    {
+        consumers::accumulate(self, consumers::Multiplied::new(), f)
+    }
+
+    /// Returns the position of the item with the minimum key, along with
+    /// that key.
+    ///
+    /// [`LendingIterator`] counterpart of [`Iterator::min_by_key()`]: since
+    /// the lent items cannot outlive the next call to `.next()`, the item
+    /// itself cannot be returned; its position (as in
+    /// [`.position()`][LendingIterator::position()]) is returned instead.
+    ///
+    /// If several items are equally minimum, the first one is picked.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [5, 1, 4, 2, 3];
+        let min =
+            array
+                .windows_mut::<2>()
+                .min_by_key(|&mut [a, b]| a + b)
+        ;
+        assert_eq!(min, Some((1, 5)));
+        ``` */
+    fn min_by_key<K> (
+        self: Self,
+        f: impl FnMut(Item<'_, Self>) -> K,
+    ) -> Option<(usize, K)>
+    where
+        Self : Sized,
+        K : Ord,
+    {
+        consumers::accumulate(self, consumers::MinKey::new(), f)
+    }
+
+    /// Returns the position of the item with the maximum key, along with
+    /// that key.
+    ///
+    /// [`LendingIterator`] counterpart of [`Iterator::max_by_key()`]: see
+    /// [`.min_by_key()`][LendingIterator::min_by_key()] for more info.
+    ///
+    /// If several items are equally maximum, the last one is picked.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [5, 1, 4, 2, 3];
+        let max =
+            array
+                .windows_mut::<2>()
+                .max_by_key(|&mut [a, b]| a.max(b))
+        ;
+        assert_eq!(max, Some((0, 5)));
+        ``` */
+    fn max_by_key<K> (
+        self: Self,
+        f: impl FnMut(Item<'_, Self>) -> K,
+    ) -> Option<(usize, K)>
+    where
+        Self : Sized,
+        K : Ord,
+    {
+        consumers::accumulate(self, consumers::MaxKey::new(), f)
+    }
+
+    /// Returns both the
+    /// [`.min_by_key()`][LendingIterator::min_by_key()] and the
+    /// [`.max_by_key()`][LendingIterator::max_by_key()] of the items, in a
+    /// single pass.
+    ///
+    /// When only one item is lent, its key is cloned so as to be both the
+    /// minimum and the maximum.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [5, 1, 4, 2, 3];
+        let minmax =
+            array
+                .windows_mut::<2>()
+                .minmax_by_key(|&mut [a, b]| a.max(b))
+        ;
+        assert_eq!(minmax, Some(((3, 3), (0, 5))));
+
+        let mut array = [42];
+        let minmax =
+            array
+                .windows_mut::<1>()
+                .minmax_by_key(|&mut [x]| x)
+        ;
+        assert_eq!(minmax, Some(((0, 42), (0, 42))));
+        ``` */
+    fn minmax_by_key<K> (
+        self: Self,
+        f: impl FnMut(Item<'_, Self>) -> K,
+    ) -> Option<((usize, K), (usize, K))>
+    where
+        Self : Sized,
+        K : Ord + Clone,
+    {
+        consumers::accumulate(self, consumers::MinMaxKey::new(), f)
+    }
+
+    /// Counts the occurrences of each owned projection of the items, obtained
+    /// through `f`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = *b"banana";
+        let counts =
+            array
+                .windows_mut::<2>()
+                .counts_by(|&mut pair| pair)
+        ;
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            [(*b"an", 2), (*b"ba", 1), (*b"na", 2)],
+        );
+        ``` */
+    #[apply(cfg_alloc)]
+    fn counts_by<K> (
+        self: Self,
+        f: impl FnMut(Item<'_, Self>) -> K,
+    ) -> ::alloc::collections::BTreeMap<K, usize>
+    where
+        Self : Sized,
+        K : Ord,
+    {
+        consumers::accumulate(self, consumers::Counts::new(), f)
+    }
+
+    /// Checks whether the items of `self` are equal to those of `other`,
+    /// as determined by `eq`.
+    ///
+    /// [`LendingIterator`] counterpart of `Iterator::eq_by()`: since the items
+    /// of two distinct [`LendingIterator`]s can coëxist, they can be compared
+    /// against each other, even when lending different types.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut bytes = *b"aBcD";
+        let mut chars = ['A', 'b', 'C', 'd'];
+        assert!(
+            bytes.windows_mut::<2>().eq_by(
+                chars.windows_mut::<2>(),
+                |&mut [a, b], &mut [c, d]| {
+                    a.eq_ignore_ascii_case(&(c as u8))
+                    &&
+                    b.eq_ignore_ascii_case(&(d as u8))
+                },
+            )
+        );
+        ``` */
+    fn eq_by<J : LendingIterator> (
+        mut self: Self,
+        mut other: J,
+        mut eq: impl FnMut(Item<'_, Self>, Item<'_, J>) -> bool,
+    ) -> bool
+    where
+        Self : Sized,
+    {
+        self.all(|item| other.next().map_or(false, |it| eq(item, it)))
+        &&
+        other.next().is_none()
+    }
+
+    /// [Lexicographically] compares the items of `self` with those of
+    /// `other`, as determined by `cmp`.
+    ///
+    /// [`LendingIterator`] counterpart of `Iterator::cmp_by()`: see
+    /// [`.eq_by()`][LendingIterator::eq_by()] for more info.
+    ///
+    /// [Lexicographically]: ::core::cmp::Ord#lexicographical-comparison
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::core::cmp::Ordering;
+        use ::lending_iterator::prelude::*;
+
+        let mut a = [1, 2, 3, 4];
+        let mut b = [1, 2, 2, 5];
+        let ordering =
+            a   .windows_mut::<2>()
+                .cmp_by(b.windows_mut::<2>(), |&mut [x, y], &mut [z, w]| {
+                    (x + y).cmp(&(z + w))
+                })
+        ;
+        // 3 == 3, then 5 > 4.
+        assert_eq!(ordering, Ordering::Greater);
+
+        let ordering =
+            a   .windows_mut::<2>()
+                .cmp_by(b.windows_mut::<3>(), |&mut [x, _], &mut [z, ..]| {
+                    x.cmp(&z)
+                })
+        ;
+        // Equal prefix, but `a`'s windows outnumber `b`'s.
+        assert_eq!(ordering, Ordering::Greater);
+        ``` */
+    fn cmp_by<J : LendingIterator> (
+        mut self: Self,
+        mut other: J,
+        mut cmp: impl FnMut(Item<'_, Self>, Item<'_, J>) -> ::core::cmp::Ordering,
+    ) -> ::core::cmp::Ordering
+    where
+        Self : Sized,
+    {
+        use ::core::cmp::Ordering;
+        let compared = self.try_for_each(|item| match other.next() {
+            | None => Err(Ordering::Greater),
+            | Some(it) => match cmp(item, it) {
+                | Ordering::Equal => Ok(()),
+                | non_eq => Err(non_eq),
+            },
+        });
+        match compared {
+            | Err(non_eq) => non_eq,
+            | Ok(()) if other.next().is_some() => Ordering::Less,
+            | Ok(()) => Ordering::Equal,
+        }
+    }
+
+    /// [Lexicographically] compares the items of `self` with those of
+    /// `other`, as determined by `partial_cmp`.
+    ///
+    /// [`LendingIterator`] counterpart of `Iterator::partial_cmp_by()`: see
+    /// [`.cmp_by()`][LendingIterator::cmp_by()] for more info.
+    ///
+    /// [Lexicographically]: ::core::cmp::Ord#lexicographical-comparison
+    fn partial_cmp_by<J : LendingIterator> (
+        mut self: Self,
+        mut other: J,
+        mut partial_cmp: impl FnMut(Item<'_, Self>, Item<'_, J>)
+                           -> Option<::core::cmp::Ordering>
+        ,
+    ) -> Option<::core::cmp::Ordering>
+    where
+        Self : Sized,
+    {
+        use ::core::cmp::Ordering;
+        let compared = self.try_for_each(|item| match other.next() {
+            | None => Err(Some(Ordering::Greater)),
+            | Some(it) => match partial_cmp(item, it) {
+                | Some(Ordering::Equal) => Ok(()),
+                | non_eq => Err(non_eq),
+            },
+        });
+        match compared {
+            | Err(non_eq) => non_eq,
+            | Ok(()) if other.next().is_some() => Some(Ordering::Less),
+            | Ok(()) => Some(Ordering::Equal),
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::lt()`].
+    ///
+    /// See [`.partial_cmp_by()`][LendingIterator::partial_cmp_by()] for more
+    /// info.
+    fn lt<J> (
+        self: Self,
+        other: J,
+    ) -> bool
+    where
+        Self : Sized,
+        J : ඞPartialOrdItems<Self>,
+    {
+        matches!(
+            other.ඞpartial_cmp_items(self),
+            Some(::core::cmp::Ordering::Less),
+        )
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::le()`].
+    ///
+    /// See [`.partial_cmp_by()`][LendingIterator::partial_cmp_by()] for more
+    /// info.
+    fn le<J> (
+        self: Self,
+        other: J,
+    ) -> bool
+    where
+        Self : Sized,
+        J : ඞPartialOrdItems<Self>,
+    {
+        matches!(
+            other.ඞpartial_cmp_items(self),
+            Some(::core::cmp::Ordering::Less | ::core::cmp::Ordering::Equal),
+        )
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::gt()`].
+    ///
+    /// See [`.partial_cmp_by()`][LendingIterator::partial_cmp_by()] for more
+    /// info.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut a = [1, 2, 3];
+        let mut b = [1, 2, 2, 9];
+        assert!(a.windows_mut::<2>().gt(b.windows_mut::<2>()));
+        assert!(a.windows_mut::<2>().ge(b.windows_mut::<2>()));
+        assert!(!a.windows_mut::<2>().lt(b.windows_mut::<2>()));
+        ``` */
+    fn gt<J> (
+        self: Self,
+        other: J,
+    ) -> bool
+    where
+        Self : Sized,
+        J : ඞPartialOrdItems<Self>,
+    {
+        matches!(
+            other.ඞpartial_cmp_items(self),
+            Some(::core::cmp::Ordering::Greater),
+        )
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::ge()`].
+    ///
+    /// See [`.partial_cmp_by()`][LendingIterator::partial_cmp_by()] for more
+    /// info.
+    fn ge<J> (
+        self: Self,
+        other: J,
+    ) -> bool
+    where
+        Self : Sized,
+        J : ඞPartialOrdItems<Self>,
+    {
+        matches!(
+            other.ඞpartial_cmp_items(self),
+            Some(::core::cmp::Ordering::Greater | ::core::cmp::Ordering::Equal),
+        )
+    }
+
+    /// Checks whether the owned keys of the items, obtained through `f`, are
+    /// sorted, by comparing each key with the previous one.
+    ///
+    /// [`LendingIterator`] counterpart of `Iterator::is_sorted_by_key()`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 5, 2, 4, 3];
+        assert!(
+            !array
+                .windows_mut::<2>()
+                .is_sorted_by_key(|&mut [a, b]| a + b)
+        );
+        assert!(
+            !array
+                .windows_mut::<2>()
+                .is_sorted_by_key(|&mut [a, b]| a.max(b))
+        );
+        assert!(
+            array
+                .windows_mut::<2>()
+                .is_sorted_by_key(|&mut [a, b]| (a + b) / 6)
+        );
+        ``` */
+    fn is_sorted_by_key<K> (
+        mut self: Self,
+        mut f: impl FnMut(Item<'_, Self>) -> K,
+    ) -> bool
+    where
+        Self : Sized,
+        K : PartialOrd,
+    {
+        self.try_fold(None, |prev: Option<K>, item| {
+            let key = f(item);
+            match prev {
+                | Some(prev) if (prev <= key).not() => Err(()),
+                | _ => Ok(Some(key)),
+            }
+        })
+        .is_ok()
+    }
+
+    /// Feeds each item, by shared reference, to every one of the
+    /// [`Consumer`][consumers::Consumer]s (_e.g._, a tuple thereof) returned
+    /// by the `consumers` closure, in a single pass, and returns their
+    /// results.
+    ///
+    /// The closure is given the [`Consumers`][consumers::Consumers]
+    /// constructors for the items of `self`, so that the closures these
+    /// take need no type annotations.
+    ///
+    /// Iteration stops as soon as none of the consumers need further items.
+    ///
+    /// See the [`consumers`] module for more info.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 4, 5];
+        let mut windows = array.windows_mut::<2>();
+        let (sum, position_of_5) = windows.fold_many(|c| (
+            c.sum_by(|&&mut [a, b]| a * b),
+            c.position(|&&mut [a, b]| a + b == 5),
+        ));
+        assert_eq!(sum, 1 * 2 + 2 * 3 + 3 * 4 + 4 * 5);
+        assert_eq!(position_of_5, Some(1));
+
+        // Iteration stops once all the consumers are done.
+        let mut windows = array.windows_mut::<2>();
+        let (any_even, ) = windows.fold_many(|c| (
+            c.any(|&&mut [a, _]| a % 2 == 0),
+        ));
+        assert!(any_even);
+        assert_eq!(windows.next(), Some(&mut [3, 4]));
+        ``` */
+    fn fold_many<C> (
+        self: &'_ mut Self,
+        consumers: impl FnOnce(consumers::Consumers<HKTItem<Self>>) -> C,
+    ) -> C::Output
+    where
+        Self : Sized,
+        C : consumers::Consumer<HKTItem<Self>>,
+    {
+        use ::core::ops::ControlFlow;
+        let mut consumers = consumers(consumers::Consumers {
+            _phantom_ty: <_>::default(),
+        });
+        let _ = self.try_for_each(|item| match consumers.feed(&item) {
+            | ControlFlow::Continue(()) => Ok(()),
+            | ControlFlow::Break(()) => Err(()),
+        });
+        consumers.finish()
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::find()`].
+    fn find<'find> (
+        self: &'find mut Self,
+        mut predicate: impl 'find + FnMut(&Item<'_, Self>) -> bool,
+    ) -> Option<Item<'find, Self>>
+    where
+        Self : Sized,
+    {
+        use ::polonius_the_crab::prelude::*;
+        let mut this = self;
+        polonius_loop!(|this| -> Option<Item<'polonius, Self>> {
+            let ret = this.next();
+            if matches!(ret, Some(ref it) if predicate(it).not()) {
+                polonius_continue!();
+            }
+            polonius_return!(ret);
+        })
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::find_map()`].
+    ///
+    /// Like [`.find()`][Self::find()], the returned value may keep borrowing
+    /// from `*self`, which makes it possible to return something derived from
+    /// the found item (_e.g._, a `&mut` into some lent window).
+    ///
+    /// All the caveats and remarks of [`.map()`][Self::map()] apply, go check
+    /// them up.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 4, 5];
+        let mut windows = array.windows_mut::<2>();
+        let second_of_first_big_window: &mut i32 =
+            windows
+                .find_map::<HKT!(&mut i32), _>(|[], &mut [a, ref mut b]| {
+                    if a + *b > 4 { Some(b) } else { None }
+                })
+                .unwrap()
+        ;
+        *second_of_first_big_window *= 10;
+        assert_eq!(windows.next(), Some(&mut [30, 4]));
+        assert_eq!(array, [1, 2, 30, 4, 5]);
+        ``` */
+    fn find_map<'find, NewItemType : HKT, F> (
+        self: &'find mut Self,
+        mut f: F,
+    ) -> Option<A!(NewItemType<'find>)>
+    where
+        F : 'find,
+        for<'next>
+            F : FnMut(
+                [&'next Self; 0],
+                Item<'next, Self>,
+            ) -> Option<A!(NewItemType<'next>)>
+        ,
+        Self : Sized,
+    {
+        use ::polonius_the_crab::prelude::*;
+        let mut this = self;
+        polonius_loop!(|this| -> Option<A!(NewItemType<'polonius>)> {
+            if let Some(item) = this.next() {
+                if let Some(found) = f([], item) {
+                    polonius_return!(Some(found));
+                }
+                polonius_continue!();
+            }
+            polonius_return!(None);
+        })
+    }
+
+    /// Fallible version of [`.find()`][Self::find()]: the search stops at the
+    /// first item for which `predicate` fails, returning that error.
+    ///
+    /// [`LendingIterator`] counterpart of `Iterator::try_find()`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [6_u32, 3, 1, 0];
+        let mut windows = array.windows_mut::<2>();
+        let found = windows.try_find(|&&mut [a, b]| {
+            a.checked_div(b).map(|q| q > 2).ok_or("division by zero")
+        });
+        assert_eq!(found, Ok(Some(&mut [3, 1])));
+
+        let mut windows = array.windows_mut::<2>();
+        let found = windows.try_find(|&&mut [a, b]| {
+            a.checked_div(b).map(|q| q > 5).ok_or("division by zero")
+        });
+        assert_eq!(found, Err("division by zero"));
+        ``` */
+    fn try_find<'find, Err> (
+        self: &'find mut Self,
+        mut predicate: impl 'find + FnMut(&Item<'_, Self>) -> Result<bool, Err>,
+    ) -> Result<Option<Item<'find, Self>>, Err>
+    where
+        Self : Sized,
+    {
+        use ::polonius_the_crab::prelude::*;
+        let mut this = self;
+        polonius_loop!(|this| -> Result<Option<Item<'polonius, Self>>, Err> {
+            let ret = this.next();
+            if let Some(ref it) = ret {
+                match predicate(it) {
+                    | Ok(true) => {},
+                    | Ok(false) => polonius_continue!(),
+                    | Err(err) => polonius_return!(Err(err)),
+                }
+            }
+            polonius_return!(Ok(ret));
+        })
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::fuse()`].
+    fn fuse (self: Self)
+      -> Fuse<Self>
+    where
+        Self : Sized,
+    {
+        Fuse(Some(self))
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::peekable()`], **provided
+    /// `Self::Item<'_>` does not depend on `'_`** (_e.g._, a
+    /// [`from_fn()`][crate::from_fn()] lending owned tokens).
+    ///
+    /// Peeking at a lent item would require storing it right next to the
+    /// iterator it borrows from: see the [trait-level docs][LendingIterator]
+    /// for more info.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut chars = "12+345".chars().into_lending_iter().peekable();
+        let mut numbers = vec![];
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() {
+                let mut n = 0;
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    n = 10 * n + digit.to_digit(10).unwrap();
+                }
+                numbers.push(n);
+            } else {
+                assert_eq!(chars.next_if_eq(&'+'), Some('+'));
+            }
+        }
+        assert_eq!(numbers, [12, 345]);
+        ``` */
+    fn peekable<T> (self: Self)
+      -> Peekable<Self, T>
+    where
+        Self : for<'any> LendingIteratorඞItem<'any, T = T>,
+        Self : Sized,
+    {
+        Peekable { iter: self, peeked: None }
+    }
+
+    /// Merges two sorted [`LendingIterator`]s into a sorted one, **provided
+    /// neither `Self::Item<'_>` nor `J::Item<'_>` depend on `'_`** (_e.g._,
+    /// after a [`.to_owned_items()`][Self::to_owned_items()]).
+    ///
+    /// The `cmp` closure compares the heads of both iterators: on
+    /// [`Ordering::Equal`][::core::cmp::Ordering::Equal], the head of `self`
+    /// is yielded first. Which iterator each item comes from is told by the
+    /// yielded [`EitherOrBoth::Left`] or [`EitherOrBoth::Right`] (never
+    /// [`EitherOrBoth::Both`]).
+    ///
+    /// Merging lent items would require storing the head of the iterator
+    /// which is not being advanced right next to the iterator it borrows
+    /// from: see the [trait-level docs][LendingIterator] for more info.
+    ///
+    /// See [`kmerge_by()`][crate::kmerge_by()] to merge more than two
+    /// iterators.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::{
+            lending_iterator::adapters::EitherOrBoth,
+            prelude::*,
+        };
+
+        let evens = lending_iterator::from_iter([0_u8, 2, 4, 6]);
+        let odds = lending_iterator::from_iter([1_u16, 3]);
+        let mut merged = evens.merge_by(odds, |&x, &y| u16::from(x).cmp(&y));
+        let mut items = vec![];
+        while let Some(item) = merged.next() {
+            items.push(match item {
+                | EitherOrBoth::Left(x) => u16::from(x),
+                | EitherOrBoth::Right(y) => y,
+                | EitherOrBoth::Both(..) => unreachable!(),
+            });
+        }
+        assert_eq!(items, [0, 1, 2, 3, 4, 6]);
+        ``` */
+    fn merge_by<J, T, U, F> (
+        self: Self,
+        other: J,
+        cmp: F,
+    ) -> MergeBy<Self, J, T, U, F>
+    where
+        Self : for<'any> LendingIteratorඞItem<'any, T = T>,
+        Self : Sized,
+        J : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = U>,
+        F : FnMut(&T, &U) -> ::core::cmp::Ordering,
+    {
+        MergeBy {
+            first: self,
+            second: other,
+            first_head: None,
+            second_head: None,
+            cmp,
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::advance_by()`].
+    ///
+    /// Advances the iterator by `count` items, yielding `Err(k)` if the
+    /// iterator got exhausted before that, where `k` is the number of steps
+    /// which could not be performed.
+    fn advance_by (
+        self: &'_ mut Self,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        for i in 0 .. count {
+            if self.next().is_none() {
+                return Err(NonZeroUsize::new(count - i).unwrap());
+            }
+        }
+        Ok(())
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::nth()`].
+    fn nth (
+        self: &'_ mut Self,
+        n: usize,
+    ) -> Option<Item<'_, Self>>
+    {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::position()`].
+    fn position<F> (
+        self: &'_ mut Self,
+        mut predicate: impl FnMut(Item<'_, Self>) -> bool,
+    ) -> Option<usize>
+    where
+        Self : Sized,
+    {
+        match
+            self.try_fold(0, |i, item| if predicate(item) {
+                Err(i)
+            } else {
+                Ok(i + 1)
+            })
+        {
+            | Err(position) => Some(position),
+            | Ok(_) => None,
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::rev()`].
+    fn rev (
+        self: Self,
+    ) -> Rev<Self>
+    where
+        Self : Sized + DoubleEndedLendingIterator,
+    {
+        Rev { iter: self }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::skip()`].
+    fn skip (
+        self: Self,
+        count: usize,
+    ) -> Skip<Self>
+    where
+        Self : Sized,
+    {
+        Skip {
+            iter: self,
+            to_skip: count.try_into().ok(),
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::skip_while()`].
+    fn skip_while<F> (
+        self: Self,
+        predicate: F,
+    ) -> SkipWhile<Self, F>
+    where
+        F : FnMut(&'_ Item<'_, Self>) -> bool,
+        Self : Sized,
+    {
+        SkipWhile { iter: self, predicate, skipped: false }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::take()`].
+    fn take (
+        self: Self,
+        count: usize,
+    ) -> Take<Self>
+    where
+        Self : Sized,
+    {
+        Take {
+            iter: self,
+            remaining: count,
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::take_while()`].
+    fn take_while<F> (
+        self: Self,
+        predicate: F,
+    ) -> TakeWhile<Self, F>
+    where
+        F : FnMut(&'_ Item<'_, Self>) -> bool,
+        Self : Sized,
+    {
+        TakeWhile { iter: self, predicate, done: false }
+    }
+
+    /// Inclusive flavor of [`.take_while()`][Self::take_while()]: yields the
+    /// items up until, **and including**, the first one for which `predicate`
+    /// returns `true`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 4, 5, 6];
+        let mut iter =
+            array
+                .windows_mut::<2>()
+                .take_until(|&&mut [_, b]| b % 4 == 0)
+        ;
+        while let Some(&mut [a, ref mut b]) = iter.next() {
+            *b += a;
+        }
+        assert_eq!(array, [1, 3, 6, 10, 5, 6]);
+        ``` */
+    fn take_until<F> (
+        self: Self,
+        predicate: F,
+    ) -> TakeUntil<Self, F>
+    where
+        F : FnMut(&'_ Item<'_, Self>) -> bool,
+        Self : Sized,
+    {
+        TakeUntil { iter: self, predicate, done: false }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::map()`].
+    ///
+    ///   - **Turbofishing the `NewItemType` is mandatory**, otherwise you'll
+    ///     run into issues with non-higher-order closures.
+    ///
+    ///     See the module-level documentation of [`crate::higher_kinded_types`]
+    ///     for more info.
+    ///
+    ///     But the TL,DR is that you'd use it as:
+    ///
+    ///     <code>lending_iter.map::\<[HKT!]\(ReturnType\<\'_\>\), _>\(</code>
+    ///
+    ///   - the second idiosyncracy is that, for technical reasons[^1] related
+    ///     to the maximally generic aspect of this API, the closure itself
+    ///     cannot just be a `Self::Item<'_> -> Feed<'_, NewItemType>` closure,
+    ///     and instead, requires that an extra `[]` dummy parameter be part
+    ///     of the signature:
+    ///
+    ///     ```rust
+    ///     # #[cfg(any)] macro_rules! ignore { /*
+    ///     lending_iter.map::<HKT…, _>(|[], item| { … })
+    ///                                  👆
+    ///     # */ }
+    ///     ```
+    ///
+    /// [^1]: In the case where `Self::Item<'_>` does _not_ depend on `'_`, the
+    /// return type then technically can't depend on it either, so Rust
+    /// complains about this (in a rather obtuse fashion). We solve this by
+    /// requiring that extra `[]` parameter which acts as a convenient-to-write
+    /// `PhantomData` which does depend on `'_`.
+    fn map<NewItemType : HKT, F> (
+        self: Self,
+        f: F,
+    ) -> Map<Self, F, NewItemType>
+    where
+        for<'next>
+            F : FnMut(
+                [&'next Self; 0],
+                Item<'next, Self>,
+            ) -> A!(NewItemType<'next>)
+        ,
+        Self : Sized,
+    {
+        Map { iter: self, map: f, _phantom_ty: <_>::default() }
+    }
+
+    pervasive_hkt_choices! {
+        (map, Map)(
+            /// Convenience method: same as [`.map()`][Self::map()], but for
+            /// hard-coding the `HKT` parameter to
+            /// <code>[HKTRef]\<R\> = [HKT!]\(\&R\)</code>.
+            ///
+            /// This alleviates the call-sites (no more turbofishing needed!)
+            /// for such pervasive use cases 🙂
+            map_to_ref: [R : ?Sized], HKTRef<R>, -> &'any R,
+            /// Convenience method: same as [`.map()`][Self::map()], but for
+            /// hard-coding the `HKT` parameter to
+            /// <code>[HKTRefMut]\<R\> = [HKT!]\(\&mut R\)</code>.
+            ///
+            /// This alleviates the call-sites (no more turbofishing needed!)
+            /// for such pervasive use cases 🙂
+            map_to_mut: [R : ?Sized], HKTRefMut<R>, -> &'any mut R,
+        ),
+    }
+
+    /// Convenience shorthand for
+    /// <code>[.map…\(…\)][Self::map()][.into_iter()][Self::into_iter()]</code>.
+    ///
+    /// When the return type of the `.map()` closure is not lending
+    /// / borrowing from `*self`, it becomes possible to call
+    /// [.into_iter()][Self::into_iter()] on it right away.
+    ///
+    /// Moreover, it makes the `[], ` closure arg hack no longer necessary.
+    ///
+    /// This convenience function encompasses both things, thence returning
+    /// an [`Iterator`] (not a [`LendingIterator`]!).
+    fn map_into_iter<F, NonLendingItem> (
+        self: Self,
+        f: F,
+    ) -> MapIntoIter<Self, F>
+    where
+        F : FnMut(Item<'_, Self>) -> NonLendingItem,
+        Self : Sized,
+    {
+        MapIntoIter(self, f)
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::filter_map()`].
+    ///
+    /// All the caveats and remarks of [`.map()`][Self::map()] apply, go check
+    /// them up.
+    fn filter_map<NewItemType : HKT, F> (
+        self: Self,
+        f: F,
+    ) -> FilterMap<Self, F, NewItemType>
+    where
+        for<'next>
+            F : FnMut(
+                [&'next Self; 0],
+                Item<'next, Self>,
+            ) -> Option<A!(NewItemType<'next>)>
+        ,
+        Self : Sized,
+    {
+        FilterMap { iter: self, map: f, _phantom_ty: <_>::default() }
+    }
+
+    pervasive_hkt_choices! {
+        (filter_map, FilterMap)(
+            /// Convenience method: same as
+            /// [`.filter_map()`][Self::filter_map()], but for hard-coding the
+            /// `HKT` parameter to <code>[HKTRef]\<R\> = [HKT!]\(\&R\)</code>.
+            ///
+            /// All the caveats and remarks of
+            /// [`.map_to_ref()`][Self::map_to_ref()] apply, go check them up.
+            filter_map_to_ref: [R : ?Sized], HKTRef<R>, -> Option<&'any R>,
+
+            /// Convenience method: same as
+            /// [`.filter_map()`][Self::filter_map()], but for hard-coding the
+            /// `HKT` parameter to <code>[HKTRefMut]\<R\> = [HKT!]\(\&mut R\)</code>.
+            ///
+            /// All the caveats and remarks of
+            /// [`.map_to_mut()`][Self::map_to_mut()] apply, go check them up.
+            filter_map_to_mut: [R : ?Sized], HKTRefMut<R>, -> Option<&'any mut R>,
+        ),
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::scan()`].
+    ///
+    /// The closure is given exclusive access to the `State` as well as the
+    /// lent item, and may return an item borrowing from **either** of them,
+    /// such as an internal buffer being lent out on each step.
+    ///
+    ///   - Iteration stops (for good) as soon as the closure returns `None`.
+    ///
+    ///   - All the caveats and remarks of [`.map()`][Self::map()] apply
+    ///     (turbofishing the `NewItemType`, and the `[]` dummy closure
+    ///     parameter), go check them up.
+    ///
+    ///   - The final `State` can be recovered through
+    ///     [`.into_state()`][Scan::into_state()].
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut prefixes = vec![];
+        let mut iter =
+            ["a", "b", "c"]
+                .into_lending_iter()
+                .scan::<HKT!(&str), _, _>(String::new(), |[], acc, s| {
+                    acc.push_str(s);
+                    Some(&acc[..])
+                })
+        ;
+        while let Some(prefix) = iter.next() {
+            prefixes.push(prefix.to_owned());
+        }
+        assert_eq!(prefixes, ["a", "ab", "abc"]);
+        assert_eq!(iter.into_state(), "abc");
+        ``` */
+    fn scan<NewItemType : HKT, State, F> (
+        self: Self,
+        initial_state: State,
+        f: F,
+    ) -> Scan<Self, State, F, NewItemType>
+    where
+        for<'next>
+            F : FnMut(
+                [&'next Self; 0],
+                &'next mut State,
+                Item<'next, Self>,
+            ) -> Option<A!(NewItemType<'next>)>
+        ,
+        Self : Sized,
+    {
+        Scan {
+            iter: self,
+            state: initial_state,
+            f,
+            done: false,
+            _phantom_ty: <_>::default(),
+        }
+    }
+
+    /// Convenience shorthand for
+    /// <code>[.filter_map…\(…\)][Self::filter_map()][.into_iter()][Self::into_iter()]</code>.
+    ///
+    /// When the return type of the `.filter_map()` closure is not lending
+    /// / borrowing from `*self`, it becomes possible to call
     /// [.into_iter()][Self::into_iter()] on it right away.
     ///
     /// Moreover, it makes the `[], ` closure arg hack no longer necessary.
@@ -615,6 +2111,169 @@ where
         FilterMapIntoIter(self, f)
     }
 
+    /// [`LendingIterator`] counterpart of [`Iterator::map_while()`].
+    ///
+    /// All the caveats and remarks of [`.map()`][Self::map()] apply, go check
+    /// them up.
+    ///
+    /// Contrary to [`.filter_map()`][Self::filter_map()], the first `None`
+    /// returned by the closure ends the iteration **for good**.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 0, 4, 5];
+        let mut iter =
+            array
+                .windows_mut::<2>()
+                .map_while::<HKT!(&mut i32), _>(|[], [a, b]| {
+                    (*b > 0).then(|| a)
+                })
+        ;
+        while let Some(a) = iter.next() {
+            *a = -*a;
+        }
+        assert!(iter.next().is_none());
+        assert_eq!(array, [-1, -2, 3, 0, 4, 5]);
+        ``` */
+    fn map_while<NewItemType : HKT, F> (
+        self: Self,
+        f: F,
+    ) -> MapWhile<Self, F, NewItemType>
+    where
+        for<'next>
+            F : FnMut(
+                [&'next Self; 0],
+                Item<'next, Self>,
+            ) -> Option<A!(NewItemType<'next>)>
+        ,
+        Self : Sized,
+    {
+        MapWhile {
+            iter: self,
+            map: f,
+            done: false,
+            _phantom_ty: <_>::default(),
+        }
+    }
+
+    pervasive_hkt_choices! {
+        (map_while, MapWhile)(
+            /// Convenience method: same as
+            /// [`.map_while()`][Self::map_while()], but for hard-coding the
+            /// `HKT` parameter to <code>[HKTRef]\<R\> = [HKT!]\(\&R\)</code>.
+            ///
+            /// All the caveats and remarks of
+            /// [`.map_to_ref()`][Self::map_to_ref()] apply, go check them up.
+            map_while_to_ref: [R : ?Sized], HKTRef<R>, -> Option<&'any R>,
+
+            /// Convenience method: same as
+            /// [`.map_while()`][Self::map_while()], but for hard-coding the
+            /// `HKT` parameter to <code>[HKTRefMut]\<R\> = [HKT!]\(\&mut R\)</code>.
+            ///
+            /// All the caveats and remarks of
+            /// [`.map_to_mut()`][Self::map_to_mut()] apply, go check them up.
+            map_while_to_mut: [R : ?Sized], HKTRefMut<R>, -> Option<&'any mut R>,
+        ),
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::flat_map()`]: maps each
+    /// item to an inner [`LendingIterator`], and lends the items of the
+    /// latter.
+    ///
+    /// The inner iterators are allowed to lend from themselves, but not to
+    /// keep borrowing from the item they were created from: since an inner
+    /// iterator has to be stored next to the outer iterator the item borrows
+    /// from, doing so would make [`FlatMap`] a self-referential `struct`
+    /// (similarly to why [`.peekable()`][Self::peekable()] cannot peek at lent
+    /// items). In practice, the inner iterators thus either stem from owned
+    /// outer items (_e.g._, those of a [`from_iter()`][crate::from_iter()]),
+    /// or merely copy data out of the lent ones, and the point of this
+    /// adapter is rather to flatten inner [`LendingIterator`]s, something
+    /// [`Iterator::flat_map()`] cannot do.
+    ///
+    /// That's why the closure does not need the `[], ` hack, nor any
+    /// turbofished `HKT` parameter: its return type does not depend on the
+    /// lifetime of the item it is fed.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut rows = [[0, 1, 2], [3, 4, 5]];
+        let mut iter =
+            rows.iter_mut()
+                .into_lending_iter()
+                .flat_map(|row| row.windows_mut::<2>())
+        ;
+        while let Some(&mut [a, ref mut b]) = iter.next() {
+            *b += a;
+        }
+        assert_eq!(rows, [[0, 1, 3], [3, 7, 12]]);
+        ``` */
+    fn flat_map<Inner, F> (
+        self: Self,
+        f: F,
+    ) -> FlatMap<Self, F, Inner>
+    where
+        Self : Sized,
+        F : FnMut(Item<'_, Self>) -> Inner,
+        Inner : LendingIterator,
+    {
+        FlatMap {
+            iter: self.fuse(),
+            map: f,
+            front: None,
+            back: None,
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Iterator::flatten()`], **provided
+    /// `Self::Item<'_>` does not depend on `'_`**.
+    ///
+    /// All the caveats and remarks of [`.flat_map()`][Self::flat_map()] apply,
+    /// go check them up.
+    ///
+    /// When the items are [`IntoIterator`]s rather than [`LendingIterator`]s,
+    /// you can directly use
+    /// <code>[.into_iter()][Self::into_iter()].[flatten()][Iterator::flatten()]</code>
+    /// instead (or
+    /// <code>[.map_into_iter(…)][Self::map_into_iter()].[flatten()][Iterator::flatten()]</code>).
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let (mut a, mut b) = ([0, 1, 2], [3, 4]);
+        let mut iter =
+            ::lending_iterator::from_iter([a.windows_mut::<2>(), b.windows_mut::<2>()])
+                .flatten()
+        ;
+        while let Some(&mut [x, ref mut y]) = iter.next() {
+            *y *= x;
+        }
+        drop(iter);
+        assert_eq!((a, b), ([0, 0, 0], [3, 12]));
+        ``` */
+    fn flatten<Inner> (self: Self)
+      -> Flatten<Self, Inner>
+    where
+        Self : for<'any> LendingIteratorඞItem<'any, T = Inner>,
+        Self : Sized,
+        Inner : LendingIterator,
+    {
+        FlatMap {
+            iter: self.fuse(),
+            map: ::core::convert::identity,
+            front: None,
+            back: None,
+        }
+    }
+
     /// Convert a <code>Self : [LendingIterator]</code> into an [`Iterator`],
     /// **provided `Self::Item<'_>` does not depend on `'_`**.
     fn into_iter<Item> (
@@ -670,6 +2329,199 @@ where
 }
 )}
 
+/// [`LendingIterator`] counterpart of [`ExactSizeIterator`].
+///
+/// Implemented by the [`LendingIterator`]s which know their exact remaining
+/// length, _i.e._, those whose [`.size_hint()`][LendingIterator::size_hint()]
+/// is exact.
+pub
+trait ExactSizeLendingIterator : LendingIterator {
+    /// [`LendingIterator`] counterpart of [`ExactSizeIterator::len()`].
+    fn len (
+        &self,
+    ) -> usize
+    {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(Some(lower), upper);
+        lower
+    }
+
+    /// Whether this iterator has no items left.
+    fn is_empty (
+        &self,
+    ) -> bool
+    {
+        self.len() == 0
+    }
+}
+
+/// [`LendingIterator`] counterpart of [`FusedIterator`].
+///
+/// Marker trait for the [`LendingIterator`]s which, once exhausted, keep
+/// returning `None` forever.
+///
+/// For most of this crate's own such iterators (_e.g._, [`windows_mut()`][
+/// crate::windows_mut()]), calling [`.fuse()`][LendingIterator::fuse()] is
+/// thus a mere pass-through.
+///
+/// [`FusedIterator`]: ::core::iter::FusedIterator
+pub
+trait FusedLendingIterator : LendingIterator {}
+
+/// [`LendingIterator`] counterpart of [`DoubleEndedIterator`].
+///
+/// ## Example
+///
+/**  - ```rust
+    use ::lending_iterator::prelude::*;
+
+    let mut array = [1, 2, 3, 4, 5];
+    // Right-to-left suffix sums.
+    let mut iter = array.windows_mut::<2>().rev();
+    while let Some(&mut [ref mut prev, next]) = iter.next() {
+        *prev += next;
+    }
+    assert_eq!(array, [15, 14, 12, 9, 5]);
+    ``` */
+pub
+trait DoubleEndedLendingIterator : LendingIterator {
+    /// [`LendingIterator`] counterpart of
+    /// [`DoubleEndedIterator::next_back()`].
+    fn next_back (
+        &mut self,
+    ) -> Option<Item<'_, Self>>
+    ;
+
+    /// [`LendingIterator`] counterpart of
+    /// [`DoubleEndedIterator::advance_back_by()`].
+    ///
+    /// See [`LendingIterator::advance_by()`] for more info.
+    fn advance_back_by (
+        &mut self,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        for i in 0 .. count {
+            if self.next_back().is_none() {
+                return Err(NonZeroUsize::new(count - i).unwrap());
+            }
+        }
+        Ok(())
+    }
+
+    /// [`LendingIterator`] counterpart of
+    /// [`DoubleEndedIterator::nth_back()`].
+    fn nth_back (
+        &mut self,
+        n: usize,
+    ) -> Option<Item<'_, Self>>
+    {
+        self.advance_back_by(n).ok()?;
+        self.next_back()
+    }
+
+    /// [`LendingIterator`] counterpart of
+    /// [`DoubleEndedIterator::try_rfold()`].
+    fn try_rfold<Acc, Err> (
+        &mut self,
+        mut acc: Acc,
+        mut f: impl FnMut(Acc, Item<'_, Self>) -> Result<Acc, Err>,
+    ) -> Result<Acc, Err>
+    {
+        while let Some(item) = self.next_back() {
+            acc = f(acc, item)?;
+        }
+        Ok(acc)
+    }
+
+    /// [`LendingIterator`] counterpart of [`DoubleEndedIterator::rfold()`].
+    fn rfold<Acc> (
+        mut self,
+        acc: Acc,
+        mut f: impl FnMut(Acc, Item<'_, Self>) -> Acc,
+    ) -> Acc
+    where
+        Self : Sized,
+    {
+        self.try_rfold(acc, |acc, item| Ok(f(acc, item)))
+            .unwrap_or_else(|unreachable: ǃ| unreachable)
+    }
+
+    /// [`LendingIterator`] counterpart of [`DoubleEndedIterator::rfind()`].
+    ///
+    /// Like [`.find()`][LendingIterator::find()], the returned item keeps
+    /// borrowing from `*self`.
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = [1, 2, 3, 4, 5];
+        let mut windows = array.windows_mut::<2>();
+        if let Some([_, last_odd]) = windows.rfind(|&&mut [a, _]| a % 2 == 1) {
+            *last_odd = 0;
+        }
+        assert_eq!(windows.next_back(), Some(&mut [2, 3]));
+        assert_eq!(array, [1, 2, 3, 0, 5]);
+        ``` */
+    fn rfind<'find> (
+        &'find mut self,
+        mut predicate: impl 'find + FnMut(&Item<'_, Self>) -> bool,
+    ) -> Option<Item<'find, Self>>
+    where
+        Self : Sized,
+    {
+        use ::polonius_the_crab::prelude::*;
+        let mut this = self;
+        polonius_loop!(|this| -> Option<Item<'polonius, Self>> {
+            let ret = this.next_back();
+            if matches!(ret, Some(ref it) if predicate(it).not()) {
+                polonius_continue!();
+            }
+            polonius_return!(ret);
+        })
+    }
+}
+
+/// [`LendingIterator`]s which can be restarted in place, _i.e._, without
+/// having to be `Clone`d beforehand.
+///
+/// This is what [`.cycle()`][LendingIterator::cycle()] relies on.
+pub
+trait Restartable : LendingIterator {
+    /// Rewinds `self` back to its initial position.
+    fn restart (
+        &mut self,
+    )
+    ;
+}
+
+/// Helper trait to express the
+/// <code>for\<\'a, \'b\> Item\<\'a, I\> : [PartialOrd]\<Item\<\'b, J\>\></code>
+/// bound of [`.lt()`][LendingIterator::lt()] & co. with `J` as the `Self`
+/// type: otherwise, Rust could eagerly (and thus wrongly) reject the bound
+/// before `J` gets inferred.
+#[doc(hidden)] /** Not part of the public API */ pub
+trait ඞPartialOrdItems<I : LendingIterator> : LendingIterator {
+    fn ඞpartial_cmp_items (self, iter: I)
+      -> Option<::core::cmp::Ordering>
+    ;
+}
+
+impl<I : LendingIterator, J : LendingIterator> ඞPartialOrdItems<I> for J
+where
+    for<'a, 'b>
+        Item<'a, I> : PartialOrd<Item<'b, J>>
+    ,
+{
+    fn ඞpartial_cmp_items (self: J, iter: I)
+      -> Option<::core::cmp::Ordering>
+    {
+        iter.partial_cmp_by(self, |a, b| a.partial_cmp(&b))
+    }
+}
+
 macro_rules! pervasive_hkt_choices {(
     ($map:ident, $Map:ident)(
         $(
@@ -14,7 +14,7 @@
             // initial state:
         ::lending_iterator::repeat_mut((&mut array, 0))
             // main logic (lending _slices_):
-            .filter_map::<HKT!(&mut [u16]), _>(|[], (array, start)| {
+            .map_while::<HKT!(&mut [u16]), _>(|[], (array, start)| {
                 let to_yield =
                     array
                         .get_mut(*start..)?
@@ -50,13 +50,34 @@
 use super::*;
 
 match_! {(
+    chain,
+    chunks,
+    cloned,
+    cycle_with,
+    dedup_by_key,
+    enumerate,
     filter,
     filter_map,
+    flat_map,
     fuse,
+    inspect,
+    intersperse_with,
     into_iter,
     map,
+    map_while,
+    merge_by,
+    peekable,
+    rev,
+    scan,
     skip,
+    skip_while,
+    step_by,
     take,
+    take_while,
+    #[apply(cfg_alloc)]
+    unique_by_key,
+    zip,
+    zip_iter,
 ) {(
     $(
         $(#[$attrs:meta])*
@@ -0,0 +1,148 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.chain()`][LendingIterator::chain()].
+pub
+struct Chain<I, J>
+where
+    I : LendingIterator,
+    J : LendingIterator,
+    J : for<'any> LendingIteratorඞItem<'any, T = Item<'any, I>>,
+{
+    /// `None` once exhausted.
+    pub(in crate)
+    first: Option<I>,
+
+    /// `None` once exhausted from the back.
+    pub(in crate)
+    second: Option<J>,
+}
+
+#[gat]
+impl<I, J> LendingIterator
+    for Chain<I, J>
+where
+    I : LendingIterator,
+    J : LendingIterator,
+    J : for<'any> LendingIteratorඞItem<'any, T = Item<'any, I>>,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut Chain<I, J>,
+    ) -> Option<Item<'_, I>>
+    {
+        let mut this = self;
+        let first_is_exhausted = polonius!(|this| -> Option<Item<'polonius, I>> {
+            if let Some(first) = &mut this.first {
+                if let item @ Some(_) = first.next() {
+                    polonius_return!(item);
+                }
+                true
+            } else {
+                false
+            }
+        });
+        if first_is_exhausted {
+            this.first = None;
+        }
+        this.second.as_mut()?.next()
+    }
+
+    fn advance_by (
+        self: &'_ mut Chain<I, J>,
+        mut count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        if let Some(first) = &mut self.first {
+            match first.advance_by(count) {
+                | Ok(()) => return Ok(()),
+                | Err(missing) => count = missing.get(),
+            }
+            self.first = None;
+        }
+        if let Some(second) = &mut self.second {
+            return second.advance_by(count);
+        }
+        NonZeroUsize::new(count).map_or(Ok(()), Err)
+    }
+
+    fn size_hint (self: &'_ Chain<I, J>)
+      -> (usize, Option<usize>)
+    {
+        let (lower_1, upper_1) =
+            self.first.as_ref().map_or((0, Some(0)), I::size_hint)
+        ;
+        let (lower_2, upper_2) =
+            self.second.as_ref().map_or((0, Some(0)), J::size_hint)
+        ;
+        (
+            lower_1.saturating_add(lower_2),
+            upper_1.and_then(|upper_1| upper_1.checked_add(upper_2?)),
+        )
+    }
+
+    fn count (self: Chain<I, J>)
+      -> usize
+    {
+        self.first.map_or(0, I::count) + self.second.map_or(0, J::count)
+    }
+}
+
+impl<I, J> DoubleEndedLendingIterator
+    for Chain<I, J>
+where
+    I : DoubleEndedLendingIterator,
+    J : DoubleEndedLendingIterator,
+    J : for<'any> LendingIteratorඞItem<'any, T = Item<'any, I>>,
+{
+    fn next_back (
+        self: &'_ mut Chain<I, J>,
+    ) -> Option<Item<'_, I>>
+    {
+        let mut this = self;
+        let second_is_exhausted = polonius!(|this| -> Option<Item<'polonius, I>> {
+            if let Some(second) = &mut this.second {
+                if let item @ Some(_) = second.next_back() {
+                    polonius_return!(item);
+                }
+                true
+            } else {
+                false
+            }
+        });
+        if second_is_exhausted {
+            this.second = None;
+        }
+        this.first.as_mut()?.next_back()
+    }
+
+    fn advance_back_by (
+        self: &'_ mut Chain<I, J>,
+        mut count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        if let Some(second) = &mut self.second {
+            match second.advance_back_by(count) {
+                | Ok(()) => return Ok(()),
+                | Err(missing) => count = missing.get(),
+            }
+            self.second = None;
+        }
+        if let Some(first) = &mut self.first {
+            return first.advance_back_by(count);
+        }
+        NonZeroUsize::new(count).map_or(Ok(()), Err)
+    }
+}
+
+impl<I, J> FusedLendingIterator
+    for Chain<I, J>
+where
+    I : FusedLendingIterator,
+    J : FusedLendingIterator,
+    J : for<'any> LendingIteratorඞItem<'any, T = Item<'any, I>>,
+{}
@@ -0,0 +1,105 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.chunks()`][LendingIterator::chunks()].
+pub
+struct Chunks<I : ExactSizeLendingIterator> {
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    chunk_size: usize,
+
+    /// Items of the current [`Chunk`] which have not been yielded yet.
+    pub(in crate)
+    remaining: usize,
+}
+
+#[gat]
+impl<I : ExactSizeLendingIterator> LendingIterator for Chunks<I> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Chunk<'next, I>
+    ;
+
+    fn next (self: &'_ mut Chunks<I>)
+      -> Option<Chunk<'_, I>>
+    {
+        if self.remaining > 0 {
+            let to_skip = ::core::mem::replace(&mut self.remaining, 0);
+            let _ = self.iter.advance_by(to_skip);
+        }
+        if self.iter.len() == 0 {
+            return None;
+        }
+        self.remaining = self.chunk_size;
+        Some(Chunk { parent: self })
+    }
+
+    fn size_hint (self: &'_ Chunks<I>)
+      -> (usize, Option<usize>)
+    {
+        let len = self.iter.len().saturating_sub(self.remaining);
+        let chunk_count =
+            len / self.chunk_size + usize::from(len % self.chunk_size != 0)
+        ;
+        (chunk_count, Some(chunk_count))
+    }
+}
+
+impl<I : ExactSizeLendingIterator> ExactSizeLendingIterator for Chunks<I> {}
+
+impl<I : ExactSizeLendingIterator> FusedLendingIterator for Chunks<I> {}
+
+/// The <code>impl [LendingIterator]</code> lent by [`Chunks`]: it lends (up
+/// to) `chunk_size` items of the parent iterator, which it mutably borrows.
+///
+/// Whatever is left of a chunk when the parent iterator is advanced gets
+/// skipped.
+pub
+struct Chunk<'parent, I : ExactSizeLendingIterator> {
+    pub(in crate)
+    parent: &'parent mut Chunks<I>,
+}
+
+#[gat]
+impl<'parent, I : ExactSizeLendingIterator> LendingIterator
+    for Chunk<'parent, I>
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (self: &'_ mut Chunk<'parent, I>)
+      -> Option<Item<'_, I>>
+    {
+        let parent = &mut *self.parent;
+        if parent.remaining == 0 {
+            return None;
+        }
+        parent.remaining -= 1;
+        let item = parent.iter.next();
+        if item.is_none() {
+            parent.remaining = 0;
+        }
+        item
+    }
+
+    fn size_hint (self: &'_ Chunk<'parent, I>)
+      -> (usize, Option<usize>)
+    {
+        let len = self.parent.iter.len().min(self.parent.remaining);
+        (len, Some(len))
+    }
+}
+
+impl<'parent, I : ExactSizeLendingIterator> ExactSizeLendingIterator
+    for Chunk<'parent, I>
+{}
+
+impl<'parent, I : ExactSizeLendingIterator> FusedLendingIterator
+    for Chunk<'parent, I>
+{}
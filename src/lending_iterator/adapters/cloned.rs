@@ -0,0 +1,125 @@
+use ::core::ops::Deref;
+
+macro_rules! deref_bridges {(
+    $(
+        $(#[doc = $doc:literal])*
+        $(#[apply($cfg:ident)])?
+        $Name:ident<I, T $(: ?$Sized:ident)?>
+        where
+            T : $Bound:path $(,)?
+        => $Output:ty = |$item:ident| $convert:expr ;
+    )*
+) => (
+    $(
+        $(#[doc = $doc])*
+        $(#[apply($cfg)])?
+        pub
+        struct $Name<I : LendingIterator, T $(: ?$Sized)?> {
+            pub(in crate)
+            iter: I,
+
+            pub(in crate)
+            _phantom_ty: ::core::marker::PhantomData<fn(&T)>,
+        }
+
+        $(#[apply($cfg)])?
+        impl<I, T $(: ?$Sized)?>
+            Iterator
+        for
+            $Name<I, T>
+        where
+            I : LendingIterator,
+            for<'any>
+                Item<'any, I> : Deref<Target = T>
+            ,
+            T : $Bound,
+        {
+            type Item = $Output;
+
+            fn next (
+                self: &'_ mut $Name<I, T>,
+            ) -> Option<$Output>
+            {
+                self.iter.next().map(|$item| $convert)
+            }
+
+            fn size_hint (self: &'_ $Name<I, T>)
+              -> (usize, Option<usize>)
+            {
+                self.iter.size_hint()
+            }
+        }
+
+        $(#[apply($cfg)])?
+        impl<I, T $(: ?$Sized)?>
+            DoubleEndedIterator
+        for
+            $Name<I, T>
+        where
+            I : DoubleEndedLendingIterator,
+            for<'any>
+                Item<'any, I> : Deref<Target = T>
+            ,
+            T : $Bound,
+        {
+            fn next_back (
+                self: &'_ mut $Name<I, T>,
+            ) -> Option<$Output>
+            {
+                self.iter.next_back().map(|$item| $convert)
+            }
+        }
+
+        $(#[apply($cfg)])?
+        impl<I, T $(: ?$Sized)?>
+            ExactSizeIterator
+        for
+            $Name<I, T>
+        where
+            I : ExactSizeLendingIterator,
+            for<'any>
+                Item<'any, I> : Deref<Target = T>
+            ,
+            T : $Bound,
+        {}
+
+        $(#[apply($cfg)])?
+        impl<I, T $(: ?$Sized)?>
+            ::core::iter::FusedIterator
+        for
+            $Name<I, T>
+        where
+            I : FusedLendingIterator,
+            for<'any>
+                Item<'any, I> : Deref<Target = T>
+            ,
+            T : $Bound,
+        {}
+    )*
+)}
+
+deref_bridges! {
+    /// The <code>impl [Iterator]</code> (not a [`LendingIterator`]!) returned
+    /// by [`.cloned()`][LendingIterator::cloned()].
+    Cloned<I, T>
+    where
+        T : Clone,
+    => T = |item| T::clone(&*item);
+
+    /// The <code>impl [Iterator]</code> (not a [`LendingIterator`]!) returned
+    /// by [`.copied()`][LendingIterator::copied()].
+    Copied<I, T>
+    where
+        T : Copy,
+    => T = |item| *item;
+}
+
+deref_bridges! {
+    /// The <code>impl [Iterator]</code> (not a [`LendingIterator`]!) returned
+    /// by [`.to_owned_items()`][LendingIterator::to_owned_items()].
+    #[apply(cfg_alloc)]
+    ToOwnedItems<I, T : ?Sized>
+    where
+        T : ::alloc::borrow::ToOwned,
+    => T::Owned = |item| T::to_owned(&*item);
+}
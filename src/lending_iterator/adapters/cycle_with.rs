@@ -0,0 +1,55 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.cycle_with()`][LendingIterator::cycle_with()].
+pub
+struct CycleWith<I, R>
+where
+    I : LendingIterator,
+    R : FnMut(&'_ mut I),
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    reset: R,
+}
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.cycle()`][LendingIterator::cycle()].
+pub
+type Cycle<I> = CycleWith<I, fn(&'_ mut I)>;
+
+#[gat]
+impl<I, R> LendingIterator
+    for CycleWith<I, R>
+where
+    I : LendingIterator,
+    R : FnMut(&'_ mut I),
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut CycleWith<I, R>,
+    ) -> Option<Item<'_, I>>
+    {
+        let mut this = self;
+        polonius!(|this| -> Option<Item<'polonius, I>> {
+            if let item @ Some(_) = this.iter.next() {
+                polonius_return!(item);
+            }
+        });
+        (this.reset)(&mut this.iter);
+        this.iter.next()
+    }
+
+    fn size_hint (self: &'_ CycleWith<I, R>)
+      -> (usize, Option<usize>)
+    {
+        let (lower, _) = self.iter.size_hint();
+        (lower, None)
+    }
+}
@@ -0,0 +1,70 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.dedup_by_key()`][LendingIterator::dedup_by_key()].
+pub
+struct DedupByKey<I, F, K>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : PartialEq,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    key: F,
+
+    /// The key of the last yielded item.
+    pub(in crate)
+    last_key: Option<K>,
+}
+
+#[gat]
+impl<I, F, K> LendingIterator
+    for DedupByKey<I, F, K>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : PartialEq,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut DedupByKey<I, F, K>,
+    ) -> Option<Item<'_, I>>
+    {
+        let Self { iter, key, last_key } = self;
+        iter.find(move |item| {
+            let key = key(item);
+            if last_key.as_ref() == Some(&key) {
+                false
+            } else {
+                *last_key = Some(key);
+                true
+            }
+        })
+    }
+
+    fn size_hint (self: &'_ DedupByKey<I, F, K>)
+      -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iter.size_hint();
+        if self.last_key.is_none() {
+            (lower.min(1), upper)
+        } else {
+            (0, upper)
+        }
+    }
+}
+
+impl<I, F, K> FusedLendingIterator
+    for DedupByKey<I, F, K>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : PartialEq,
+{}
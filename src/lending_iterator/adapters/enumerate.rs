@@ -0,0 +1,78 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.enumerate()`][LendingIterator::enumerate()].
+pub
+struct Enumerate<I : LendingIterator> {
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    count: usize,
+}
+
+#[gat]
+impl<I : LendingIterator> LendingIterator for Enumerate<I> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        (usize, Item<'next, I>)
+    ;
+
+    fn next (self: &'_ mut Enumerate<I>)
+      -> Option<(usize, Item<'_, I>)>
+    {
+        let item = self.iter.next()?;
+        let idx = self.count;
+        self.count += 1;
+        Some((idx, item))
+    }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut Enumerate<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        let ret = self.iter.advance_by(count);
+        self.count += count - ret.err().map_or(0, NonZeroUsize::get);
+        ret
+    }
+
+    fn size_hint (self: &'_ Enumerate<I>)
+      -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+
+    fn count (self: Enumerate<I>)
+      -> usize
+    {
+        self.iter.count()
+    }
+}
+
+impl<I> DoubleEndedLendingIterator
+    for Enumerate<I>
+where
+    I : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back (self: &'_ mut Enumerate<I>)
+      -> Option<(usize, Item<'_, I>)>
+    {
+        let len = self.iter.len();
+        let item = self.iter.next_back()?;
+        Some((self.count + len - 1, item))
+    }
+
+    fn advance_back_by (
+        self: &'_ mut Enumerate<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        self.iter.advance_back_by(count)
+    }
+}
+
+impl<I : ExactSizeLendingIterator> ExactSizeLendingIterator for Enumerate<I> {}
+
+impl<I : FusedLendingIterator> FusedLendingIterator for Enumerate<I> {}
@@ -33,4 +33,32 @@ where
     {
         self.iter.find(&mut self.should_yield)
     }
+
+    fn size_hint (self: &'_ Filter<I, F>)
+      -> (usize, Option<usize>)
+    {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
 }
+
+impl<I, F> DoubleEndedLendingIterator
+    for Filter<I, F>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    fn next_back (
+        self: &'_ mut Filter<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        self.iter.rfind(&mut self.should_yield)
+    }
+}
+
+impl<I, F> FusedLendingIterator
+    for Filter<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{}
@@ -46,7 +46,54 @@ where
         self: &'_ mut FilterMap<I, F, NewItemType>,
     ) -> Option<A!(NewItemType<'_>)>
     {
-        self.iter.next().and_then(|item| (self.map)([], item))
+        let Self { iter, map, .. } = self;
+        let mut iter = iter;
+        polonius_loop!(|iter| -> Option<A!(NewItemType<'polonius>)> {
+            if let Some(item) = iter.next() {
+                if let Some(mapped) = map([], item) {
+                    polonius_return!(Some(mapped));
+                }
+                polonius_continue!();
+            }
+            polonius_return!(None);
+        })
+    }
+
+    fn size_hint (self: &'_ FilterMap<I, F, NewItemType>)
+      -> (usize, Option<usize>)
+    {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, NewItemType, F> DoubleEndedLendingIterator
+    for FilterMap<I, F, NewItemType>
+where
+    I : DoubleEndedLendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{
+    fn next_back (
+        self: &'_ mut FilterMap<I, F, NewItemType>,
+    ) -> Option<A!(NewItemType<'_>)>
+    {
+        let Self { iter, map, .. } = self;
+        let mut iter = iter;
+        polonius_loop!(|iter| -> Option<A!(NewItemType<'polonius>)> {
+            if let Some(item) = iter.next_back() {
+                if let Some(mapped) = map([], item) {
+                    polonius_return!(Some(mapped));
+                }
+                polonius_continue!();
+            }
+            polonius_return!(None);
+        })
     }
 }
 
@@ -79,6 +126,39 @@ where
         self: &'_ mut FilterMapIntoIter<I, F>,
     ) -> Option<R>
     {
-        self.0.next().and_then(&mut self.1)
+        while let Some(item) = self.0.next() {
+            if let Some(mapped) = (self.1)(item) {
+                return Some(mapped);
+            }
+        }
+        None
+    }
+
+    fn size_hint (self: &'_ FilterMapIntoIter<I, F>)
+      -> (usize, Option<usize>)
+    {
+        let (_, upper) = self.0.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, F, R>
+    DoubleEndedIterator
+for
+    FilterMapIntoIter<I, F>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(Item<'_, I>) -> Option<R>,
+{
+    fn next_back (
+        self: &'_ mut FilterMapIntoIter<I, F>,
+    ) -> Option<R>
+    {
+        while let Some(item) = self.0.next_back() {
+            if let Some(mapped) = (self.1)(item) {
+                return Some(mapped);
+            }
+        }
+        None
     }
 }
@@ -0,0 +1,138 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.flat_map()`][LendingIterator::flat_map()].
+pub
+struct FlatMap<I, F, Inner>
+where
+    I : LendingIterator,
+    F : FnMut(Item<'_, I>) -> Inner,
+    Inner : LendingIterator,
+{
+    pub(in crate)
+    iter: Fuse<I>,
+
+    pub(in crate)
+    map: F,
+
+    /// The inner iterator currently being iterated over from the front.
+    pub(in crate)
+    front: Option<Inner>,
+
+    /// The inner iterator currently being iterated over from the back.
+    pub(in crate)
+    back: Option<Inner>,
+}
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.flatten()`][LendingIterator::flatten()].
+pub
+type Flatten<I, Inner> = FlatMap<I, fn(Inner) -> Inner, Inner>;
+
+#[gat]
+impl<I, F, Inner> LendingIterator
+    for FlatMap<I, F, Inner>
+where
+    I : LendingIterator,
+    F : FnMut(Item<'_, I>) -> Inner,
+    Inner : LendingIterator,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, Inner>
+    ;
+
+    fn next (
+        self: &'_ mut FlatMap<I, F, Inner>,
+    ) -> Option<Item<'_, Inner>>
+    {
+        let mut this = self;
+        loop {
+            polonius!(|this| -> Option<Item<'polonius, Inner>> {
+                if let Some(inner) = &mut this.front {
+                    if let item @ Some(_) = inner.next() {
+                        polonius_return!(item);
+                    }
+                }
+            });
+            match this.iter.next() {
+                | Some(item) => this.front = Some((this.map)(item)),
+                | None => break,
+            }
+        }
+        this.front = None;
+        polonius!(|this| -> Option<Item<'polonius, Inner>> {
+            if let Some(inner) = &mut this.back {
+                if let item @ Some(_) = inner.next() {
+                    polonius_return!(item);
+                }
+            }
+        });
+        this.back = None;
+        None
+    }
+
+    fn size_hint (self: &'_ FlatMap<I, F, Inner>)
+      -> (usize, Option<usize>)
+    {
+        let (lower_1, upper_1) =
+            self.front.as_ref().map_or((0, Some(0)), Inner::size_hint)
+        ;
+        let (lower_2, upper_2) =
+            self.back.as_ref().map_or((0, Some(0)), Inner::size_hint)
+        ;
+        let lower = lower_1.saturating_add(lower_2);
+        match (self.iter.size_hint(), upper_1, upper_2) {
+            | ((0, Some(0)), Some(upper_1), Some(upper_2)) => {
+                (lower, upper_1.checked_add(upper_2))
+            },
+            | _ => (lower, None),
+        }
+    }
+}
+
+impl<I, F, Inner> DoubleEndedLendingIterator
+    for FlatMap<I, F, Inner>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(Item<'_, I>) -> Inner,
+    Inner : DoubleEndedLendingIterator,
+{
+    fn next_back (
+        self: &'_ mut FlatMap<I, F, Inner>,
+    ) -> Option<Item<'_, Inner>>
+    {
+        let mut this = self;
+        loop {
+            polonius!(|this| -> Option<Item<'polonius, Inner>> {
+                if let Some(inner) = &mut this.back {
+                    if let item @ Some(_) = inner.next_back() {
+                        polonius_return!(item);
+                    }
+                }
+            });
+            match this.iter.next_back() {
+                | Some(item) => this.back = Some((this.map)(item)),
+                | None => break,
+            }
+        }
+        this.back = None;
+        polonius!(|this| -> Option<Item<'polonius, Inner>> {
+            if let Some(inner) = &mut this.front {
+                if let item @ Some(_) = inner.next_back() {
+                    polonius_return!(item);
+                }
+            }
+        });
+        this.front = None;
+        None
+    }
+}
+
+impl<I, F, Inner> FusedLendingIterator
+    for FlatMap<I, F, Inner>
+where
+    I : LendingIterator,
+    F : FnMut(Item<'_, I>) -> Inner,
+    Inner : LendingIterator,
+{}
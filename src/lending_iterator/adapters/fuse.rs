@@ -1,5 +1,8 @@
 /// The <code>impl [LendingIterator]</code> returned by [`.fuse()`][
 /// LendingIterator::fuse()].
+///
+/// When `I` is statically known to be fused (_e.g._, for
+/// [`windows_mut()`][crate::windows_mut()]), this is a mere pass-through.
 pub
 struct Fuse<I : LendingIterator>(
     pub(in crate) Option<I>,
@@ -14,9 +17,14 @@ impl<I : LendingIterator> LendingIterator for Fuse<I> {
         Item<'next, I>
     ;
 
-    fn next (self: &'_ mut Self)
+    const ඞIS_FUSED: bool = true;
+
+    fn next (self: &'_ mut Fuse<I>)
       -> Option<Item<'_, I>>
     {
+        if I::ඞIS_FUSED {
+            return self.0.as_mut()?.next();
+        }
         let mut this = self;
         let got_none = polonius!(|this| -> Option<Item<'polonius, I>> {
             if let Some(iter) = &mut this.0 {
@@ -34,4 +42,40 @@ impl<I : LendingIterator> LendingIterator for Fuse<I> {
         }
         None
     }
+
+    fn size_hint (self: &'_ Fuse<I>)
+      -> (usize, Option<usize>)
+    {
+        self.0.as_ref().map_or((0, Some(0)), I::size_hint)
+    }
+}
+
+impl<I : DoubleEndedLendingIterator> DoubleEndedLendingIterator for Fuse<I> {
+    fn next_back (self: &'_ mut Fuse<I>)
+      -> Option<Item<'_, I>>
+    {
+        if I::ඞIS_FUSED {
+            return self.0.as_mut()?.next_back();
+        }
+        let mut this = self;
+        let got_none = polonius!(|this| -> Option<Item<'polonius, I>> {
+            if let Some(iter) = &mut this.0 {
+                if let item @ Some(_) = iter.next_back() {
+                    polonius_return!(item);
+                } else {
+                    true
+                }
+            } else {
+                false
+            }
+        });
+        if got_none {
+            this.0 = None;
+        }
+        None
+    }
 }
+
+impl<I : ExactSizeLendingIterator> ExactSizeLendingIterator for Fuse<I> {}
+
+impl<I : LendingIterator> FusedLendingIterator for Fuse<I> {}
@@ -0,0 +1,149 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.inspect()`][LendingIterator::inspect()].
+pub
+struct Inspect<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>),
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    f: F,
+}
+
+#[gat]
+impl<I, F> LendingIterator
+    for Inspect<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>),
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut Inspect<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        let item = self.iter.next()?;
+        (self.f)(&item);
+        Some(item)
+    }
+
+    fn size_hint (self: &'_ Inspect<I, F>)
+      -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedLendingIterator
+    for Inspect<I, F>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>),
+{
+    fn next_back (
+        self: &'_ mut Inspect<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        let item = self.iter.next_back()?;
+        (self.f)(&item);
+        Some(item)
+    }
+}
+
+impl<I, F> ExactSizeLendingIterator
+    for Inspect<I, F>
+where
+    I : ExactSizeLendingIterator,
+    F : FnMut(&'_ Item<'_, I>),
+{}
+
+impl<I, F> FusedLendingIterator
+    for Inspect<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>),
+{}
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.inspect_mut()`][LendingIterator::inspect_mut()].
+pub
+struct InspectMut<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ mut Item<'_, I>),
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    f: F,
+}
+
+#[gat]
+impl<I, F> LendingIterator
+    for InspectMut<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ mut Item<'_, I>),
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut InspectMut<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        let mut item = self.iter.next()?;
+        (self.f)(&mut item);
+        Some(item)
+    }
+
+    fn size_hint (self: &'_ InspectMut<I, F>)
+      -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedLendingIterator
+    for InspectMut<I, F>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(&'_ mut Item<'_, I>),
+{
+    fn next_back (
+        self: &'_ mut InspectMut<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        let mut item = self.iter.next_back()?;
+        (self.f)(&mut item);
+        Some(item)
+    }
+}
+
+impl<I, F> ExactSizeLendingIterator
+    for InspectMut<I, F>
+where
+    I : ExactSizeLendingIterator,
+    F : FnMut(&'_ mut Item<'_, I>),
+{}
+
+impl<I, F> FusedLendingIterator
+    for InspectMut<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ mut Item<'_, I>),
+{}
@@ -0,0 +1,84 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.intersperse_with()`][LendingIterator::intersperse_with()].
+pub
+struct IntersperseWith<I, F, Sep>
+where
+    I : LendingIterator,
+    F : FnMut() -> Sep,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    separator: F,
+
+    /// Whether the next item of `iter` is to be preceded by a separator
+    /// (_i.e._, whether an item has been yielded after the last separator).
+    pub(in crate)
+    needs_separator: bool,
+
+    pub(in crate)
+    _phantom_ty: ::core::marker::PhantomData<fn() -> Sep>,
+}
+
+#[gat]
+impl<I, F, Sep> LendingIterator
+    for IntersperseWith<I, F, Sep>
+where
+    I : LendingIterator,
+    F : FnMut() -> Sep,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        EitherOrBoth<Item<'next, I>, Sep>
+    ;
+
+    fn next (
+        self: &'_ mut IntersperseWith<I, F, Sep>,
+    ) -> Option<EitherOrBoth<Item<'_, I>, Sep>>
+    {
+        let Self { iter, separator, needs_separator, .. } = self;
+        if ::core::mem::replace(needs_separator, true).not() {
+            return Some(EitherOrBoth::Left(iter.next()?));
+        }
+        if iter.size_hint().0 > 0 {
+            // There is a next item, so the separator can be yielded on its own.
+            *needs_separator = false;
+            return Some(EitherOrBoth::Right(separator()));
+        }
+        // Otherwise, whether to yield a separator can only be known by
+        // consuming the next item, which thus has to be yielded alongside it.
+        let item = iter.next()?;
+        Some(EitherOrBoth::Both(item, separator()))
+    }
+
+    fn size_hint (self: &'_ IntersperseWith<I, F, Sep>)
+      -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iter.size_hint();
+        // Every item but the very first one is preceded by a separator.
+        let first = |len: usize| {
+            usize::from(self.needs_separator.not() && len > 0)
+        };
+        (
+            lower.saturating_mul(2) - first(lower),
+            upper.and_then(|upper| Some(upper.checked_mul(2)? - first(upper))),
+        )
+    }
+}
+
+impl<I, F, Sep> ExactSizeLendingIterator
+    for IntersperseWith<I, F, Sep>
+where
+    I : ExactSizeLendingIterator,
+    F : FnMut() -> Sep,
+{}
+
+impl<I, F, Sep> FusedLendingIterator
+    for IntersperseWith<I, F, Sep>
+where
+    I : FusedLendingIterator,
+    F : FnMut() -> Sep,
+{}
@@ -33,4 +33,47 @@ where
     {
         self.0.next()
     }
+
+    fn size_hint (self: &'_ IntoIter<I>)
+      -> (usize, Option<usize>)
+    {
+        self.0.size_hint()
+    }
+}
+
+impl<Item, I : ?Sized + DoubleEndedLendingIterator>
+    DoubleEndedIterator
+for
+    IntoIter<I>
+where
+    for<'any>
+        I : LendingIteratorඞItem<'any, T = Item>
+    ,
+{
+    fn next_back (
+        self: &'_ mut IntoIter<I>,
+    ) -> Option<Item>
+    {
+        self.0.next_back()
+    }
 }
+
+impl<Item, I : ?Sized + ExactSizeLendingIterator>
+    ExactSizeIterator
+for
+    IntoIter<I>
+where
+    for<'any>
+        I : LendingIteratorඞItem<'any, T = Item>
+    ,
+{}
+
+impl<Item, I : ?Sized + FusedLendingIterator>
+    ::core::iter::FusedIterator
+for
+    IntoIter<I>
+where
+    for<'any>
+        I : LendingIteratorඞItem<'any, T = Item>
+    ,
+{}
@@ -48,8 +48,60 @@ where
     {
         self.iter.next().map(|item| (self.map)([], item))
     }
+
+    fn size_hint (self: &'_ Map<I, F, NewItemType>)
+      -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
 }
 
+impl<I, NewItemType, F> DoubleEndedLendingIterator
+    for Map<I, F, NewItemType>
+where
+    I : DoubleEndedLendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> A!(NewItemType<'any>)
+    ,
+{
+    fn next_back (
+        self: &'_ mut Map<I, F, NewItemType>,
+    ) -> Option<A!(NewItemType<'_>)>
+    {
+        self.iter.next_back().map(|item| (self.map)([], item))
+    }
+}
+
+impl<I, NewItemType, F> ExactSizeLendingIterator
+    for Map<I, F, NewItemType>
+where
+    I : ExactSizeLendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> A!(NewItemType<'any>)
+    ,
+{}
+
+impl<I, NewItemType, F> FusedLendingIterator
+    for Map<I, F, NewItemType>
+where
+    I : FusedLendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> A!(NewItemType<'any>)
+    ,
+{}
+
 /// The <code>impl [LendingIterator]</code> returned by
 /// [`.map_into_iter()`][LendingIterator::map_into_iter()].
 pub
@@ -81,4 +133,44 @@ where
     {
         self.0.next().map(&mut self.1)
     }
+
+    fn size_hint (self: &'_ MapIntoIter<I, F>)
+      -> (usize, Option<usize>)
+    {
+        self.0.size_hint()
+    }
 }
+
+impl<I, F, R>
+    DoubleEndedIterator
+for
+    MapIntoIter<I, F>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(Item<'_, I>) -> R,
+{
+    fn next_back (
+        self: &'_ mut MapIntoIter<I, F>,
+    ) -> Option<R>
+    {
+        self.0.next_back().map(&mut self.1)
+    }
+}
+
+impl<I, F, R>
+    ExactSizeIterator
+for
+    MapIntoIter<I, F>
+where
+    I : ExactSizeLendingIterator,
+    F : FnMut(Item<'_, I>) -> R,
+{}
+
+impl<I, F, R>
+    ::core::iter::FusedIterator
+for
+    MapIntoIter<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(Item<'_, I>) -> R,
+{}
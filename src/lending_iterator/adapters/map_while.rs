@@ -0,0 +1,87 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.map_while()`][LendingIterator::map_while()].
+pub
+struct MapWhile<I, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    map: F,
+
+    pub(in crate)
+    done: bool,
+
+    pub(in crate)
+    _phantom_ty: ::core::marker::PhantomData<fn() -> NewItemType>,
+}
+
+#[gat]
+impl<I, NewItemType, F> LendingIterator
+    for MapWhile<I, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        A!(NewItemType<'next>)
+    ;
+
+    const ඞIS_FUSED: bool = true;
+
+    fn next (
+        self: &'_ mut MapWhile<I, F, NewItemType>,
+    ) -> Option<A!(NewItemType<'_>)>
+    {
+        if self.done {
+            return None;
+        }
+        let ret = self.iter.next().and_then(|item| (self.map)([], item));
+        self.done = ret.is_none();
+        ret
+    }
+
+    fn size_hint (self: &'_ MapWhile<I, F, NewItemType>)
+      -> (usize, Option<usize>)
+    {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+/// Once its closure has returned `None`, `MapWhile` keeps returning `None`,
+/// whether `I` is fused or not.
+impl<I, NewItemType, F> FusedLendingIterator
+    for MapWhile<I, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{}
@@ -0,0 +1,104 @@
+use ::core::cmp::Ordering;
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.merge_by()`][LendingIterator::merge_by()].
+pub
+struct MergeBy<I, J, T, U, F>
+where
+    I : LendingIterator,
+    J : LendingIterator,
+{
+    pub(in crate)
+    first: I,
+
+    pub(in crate)
+    second: J,
+
+    /// The head of `first` which has not been yielded yet, if any.
+    ///
+    /// `Some(None)` when `first` is exhausted.
+    pub(in crate)
+    first_head: Option<Option<T>>,
+
+    /// The head of `second` which has not been yielded yet, if any.
+    ///
+    /// `Some(None)` when `second` is exhausted.
+    pub(in crate)
+    second_head: Option<Option<U>>,
+
+    pub(in crate)
+    cmp: F,
+}
+
+#[gat]
+impl<I, J, T, U, F> LendingIterator for MergeBy<I, J, T, U, F>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+    J : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = U>,
+    F : FnMut(&T, &U) -> Ordering,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        EitherOrBoth<T, U>
+    ;
+
+    fn next (self: &'_ mut MergeBy<I, J, T, U, F>)
+      -> Option<EitherOrBoth<T, U>>
+    {
+        let Self { first, second, first_head, second_head, cmp } = self;
+        let first_goes_first = match (
+            first_head.get_or_insert_with(|| first.next()),
+            second_head.get_or_insert_with(|| second.next()),
+        ) {
+            | (Some(fst), Some(snd)) => cmp(fst, snd) != Ordering::Greater,
+            | (Some(_), None) => true,
+            | (None, Some(_)) => false,
+            | (None, None) => return None,
+        };
+        Some(if first_goes_first {
+            EitherOrBoth::Left(first_head.take()??)
+        } else {
+            EitherOrBoth::Right(second_head.take()??)
+        })
+    }
+
+    fn size_hint (self: &'_ MergeBy<I, J, T, U, F>)
+      -> (usize, Option<usize>)
+    {
+        fn with_head<T> (
+            head: &'_ Option<Option<T>>,
+            (lower, upper): (usize, Option<usize>),
+        ) -> (usize, Option<usize>)
+        {
+            match *head {
+                | Some(None) => (0, Some(0)),
+                | Some(Some(_)) => (
+                    lower.saturating_add(1),
+                    upper.and_then(|upper| upper.checked_add(1)),
+                ),
+                | None => (lower, upper),
+            }
+        }
+        let (fst_lower, fst_upper) =
+            with_head(&self.first_head, self.first.size_hint())
+        ;
+        let (snd_lower, snd_upper) =
+            with_head(&self.second_head, self.second.size_hint())
+        ;
+        (
+            fst_lower.saturating_add(snd_lower),
+            fst_upper.and_then(|fst| fst.checked_add(snd_upper?)),
+        )
+    }
+}
+
+/// Once a source has run out, it is no longer polled, so `MergeBy` keeps
+/// returning `None` once both have, whether they are fused or not.
+impl<I, J, T, U, F> FusedLendingIterator for MergeBy<I, J, T, U, F>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+    J : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = U>,
+    F : FnMut(&T, &U) -> Ordering,
+{}
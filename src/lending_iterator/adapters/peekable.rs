@@ -0,0 +1,135 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.peekable()`][LendingIterator::peekable()].
+pub
+struct Peekable<I : LendingIterator, T> {
+    pub(in crate)
+    iter: I,
+
+    /// `Some(None)` when the peek hit the end of `iter`.
+    pub(in crate)
+    peeked: Option<Option<T>>,
+}
+
+impl<I, T> Peekable<I, T>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+{
+    /// [`LendingIterator`] counterpart of [`Peekable::peek()`].
+    ///
+    /// [`Peekable::peek()`]: ::core::iter::Peekable::peek()
+    pub
+    fn peek (self: &'_ mut Peekable<I, T>)
+      -> Option<&'_ T>
+    {
+        let Self { iter, peeked } = self;
+        peeked.get_or_insert_with(|| iter.next()).as_ref()
+    }
+
+    /// [`LendingIterator`] counterpart of [`Peekable::peek_mut()`].
+    ///
+    /// [`Peekable::peek_mut()`]: ::core::iter::Peekable::peek_mut()
+    pub
+    fn peek_mut (self: &'_ mut Peekable<I, T>)
+      -> Option<&'_ mut T>
+    {
+        let Self { iter, peeked } = self;
+        peeked.get_or_insert_with(|| iter.next()).as_mut()
+    }
+
+    /// [`LendingIterator`] counterpart of [`Peekable::next_if()`].
+    ///
+    /// [`Peekable::next_if()`]: ::core::iter::Peekable::next_if()
+    pub
+    fn next_if (
+        self: &'_ mut Peekable<I, T>,
+        predicate: impl FnOnce(&T) -> bool,
+    ) -> Option<T>
+    {
+        match self.next() {
+            | Some(item) if predicate(&item) => Some(item),
+            | next => {
+                self.peeked = Some(next);
+                None
+            },
+        }
+    }
+
+    /// [`LendingIterator`] counterpart of [`Peekable::next_if_eq()`].
+    ///
+    /// [`Peekable::next_if_eq()`]: ::core::iter::Peekable::next_if_eq()
+    pub
+    fn next_if_eq<U : ?Sized> (
+        self: &'_ mut Peekable<I, T>,
+        expected: &'_ U,
+    ) -> Option<T>
+    where
+        T : PartialEq<U>,
+    {
+        self.next_if(|next| next == expected)
+    }
+}
+
+#[gat]
+impl<I, T> LendingIterator for Peekable<I, T>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        T
+    ;
+
+    fn next (self: &'_ mut Peekable<I, T>)
+      -> Option<T>
+    {
+        match self.peeked.take() {
+            | Some(peeked) => peeked,
+            | None => self.iter.next(),
+        }
+    }
+
+    fn size_hint (self: &'_ Peekable<I, T>)
+      -> (usize, Option<usize>)
+    {
+        let peeked = match self.peeked {
+            | Some(None) => return (0, Some(0)),
+            | Some(Some(_)) => 1,
+            | None => 0,
+        };
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+impl<I, T> DoubleEndedLendingIterator for Peekable<I, T>
+where
+    I : DoubleEndedLendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+{
+    fn next_back (self: &'_ mut Peekable<I, T>)
+      -> Option<T>
+    {
+        match self.peeked {
+            | Some(None) => None,
+            | Some(Some(_)) => match self.iter.next_back() {
+                | None => self.peeked.take().flatten(),
+                | some => some,
+            },
+            | None => self.iter.next_back(),
+        }
+    }
+}
+
+impl<I, T> ExactSizeLendingIterator for Peekable<I, T>
+where
+    I : ExactSizeLendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+{}
+
+impl<I, T> FusedLendingIterator for Peekable<I, T>
+where
+    I : FusedLendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+{}
@@ -0,0 +1,85 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.rev()`][LendingIterator::rev()].
+pub
+struct Rev<I : DoubleEndedLendingIterator> {
+    pub(in crate)
+    iter: I,
+}
+
+#[gat]
+impl<I : DoubleEndedLendingIterator> LendingIterator for Rev<I> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    #[inline]
+    fn next (self: &'_ mut Rev<I>)
+      -> Option<Item<'_, I>>
+    {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut Rev<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        self.iter.advance_back_by(count)
+    }
+
+    #[inline]
+    fn nth (
+        self: &'_ mut Rev<I>,
+        n: usize,
+    ) -> Option<Item<'_, I>>
+    {
+        self.iter.nth_back(n)
+    }
+
+    fn size_hint (self: &'_ Rev<I>)
+      -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<I : DoubleEndedLendingIterator> DoubleEndedLendingIterator for Rev<I> {
+    #[inline]
+    fn next_back (self: &'_ mut Rev<I>)
+      -> Option<Item<'_, I>>
+    {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn advance_back_by (
+        self: &'_ mut Rev<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        self.iter.advance_by(count)
+    }
+
+    #[inline]
+    fn nth_back (
+        self: &'_ mut Rev<I>,
+        n: usize,
+    ) -> Option<Item<'_, I>>
+    {
+        self.iter.nth(n)
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Rev<I>
+where
+    I : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{}
+
+impl<I> FusedLendingIterator for Rev<I>
+where
+    I : DoubleEndedLendingIterator + FusedLendingIterator,
+{}
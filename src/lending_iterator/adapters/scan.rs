@@ -0,0 +1,116 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.scan()`][LendingIterator::scan()].
+pub
+struct Scan<I, State, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            &'any mut State,
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    state: State,
+
+    pub(in crate)
+    f: F,
+
+    pub(in crate)
+    done: bool,
+
+    pub(in crate)
+    _phantom_ty: ::core::marker::PhantomData<fn() -> NewItemType>,
+}
+
+impl<I, State, F, NewItemType>
+    Scan<I, State, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            &'any mut State,
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{
+    /// Consumes the adapter to get back the (final) `State`.
+    pub
+    fn into_state (self: Scan<I, State, F, NewItemType>)
+      -> State
+    {
+        self.state
+    }
+}
+
+#[gat]
+impl<I, State, F, NewItemType> LendingIterator
+    for Scan<I, State, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            &'any mut State,
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        A!(NewItemType<'next>)
+    ;
+
+    const ඞIS_FUSED: bool = true;
+
+    fn next (
+        self: &'_ mut Scan<I, State, F, NewItemType>,
+    ) -> Option<A!(NewItemType<'_>)>
+    {
+        if self.done {
+            return None;
+        }
+        let Self { iter, state, f, done, .. } = self;
+        let ret = iter.next().and_then(|item| f([], state, item));
+        *done = ret.is_none();
+        ret
+    }
+
+    fn size_hint (self: &'_ Scan<I, State, F, NewItemType>)
+      -> (usize, Option<usize>)
+    {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+/// Once its closure has returned `None`, or `I` has run out, `Scan` keeps
+/// returning `None`, whether `I` is fused or not.
+impl<I, State, F, NewItemType> FusedLendingIterator
+    for Scan<I, State, F, NewItemType>
+where
+    I : LendingIterator,
+    NewItemType : HKT,
+    for<'any>
+        F : FnMut(
+            [&'any I; 0],
+            &'any mut State,
+            Item<'any, I>,
+        ) -> Option<A!(NewItemType<'any>)>
+    ,
+{}
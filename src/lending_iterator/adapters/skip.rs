@@ -13,8 +13,7 @@ struct Skip<I : LendingIterator> {
 
 fn ensure_skipped(it: &mut Skip<impl LendingIterator>) {
     if let Some(to_skip) = it.to_skip.take() {
-        // nth(n) skips n+1
-        it.iter.nth(to_skip.get() - 1);
+        let _ = it.iter.advance_by(to_skip.get());
     }
 }
 
@@ -44,4 +43,29 @@ impl<I : LendingIterator> LendingIterator for Skip<I> {
         ensure_skipped(self);
         self.iter.nth(n)
     }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut Skip<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        ensure_skipped(self);
+        self.iter.advance_by(count)
+    }
+
+    fn size_hint (self: &'_ Skip<I>)
+      -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iter.size_hint();
+        let to_skip = self.to_skip.map_or(0, NonZeroUsize::get);
+        (
+            lower.saturating_sub(to_skip),
+            upper.map(|upper| upper.saturating_sub(to_skip)),
+        )
+    }
 }
+
+impl<I : ExactSizeLendingIterator> ExactSizeLendingIterator for Skip<I> {}
+
+impl<I : FusedLendingIterator> FusedLendingIterator for Skip<I> {}
@@ -0,0 +1,63 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.skip_while()`][LendingIterator::skip_while()].
+pub
+struct SkipWhile<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    predicate: F,
+
+    pub(in crate)
+    skipped: bool,
+}
+
+#[gat]
+impl<I, F> LendingIterator
+    for SkipWhile<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut SkipWhile<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        if self.skipped {
+            return self.iter.next();
+        }
+        let Self { iter, predicate, skipped } = self;
+        *skipped = true;
+        // `.find()` takes care of the polonius-requiring borrow juggling.
+        iter.find(move |item| predicate(item).not())
+    }
+
+    fn size_hint (self: &'_ SkipWhile<I, F>)
+      -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iter.size_hint();
+        if self.skipped {
+            (lower, upper)
+        } else {
+            (0, upper)
+        }
+    }
+}
+
+impl<I, F> FusedLendingIterator
+    for SkipWhile<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{}
@@ -0,0 +1,74 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.step_by()`][LendingIterator::step_by()].
+pub
+struct StepBy<I : LendingIterator> {
+    pub(in crate)
+    iter: I,
+
+    /// The `step` given to [`.step_by()`][LendingIterator::step_by()], minus
+    /// one.
+    pub(in crate)
+    step_minus_one: usize,
+
+    /// Whether the first item has yet to be yielded.
+    pub(in crate)
+    first_take: bool,
+}
+
+#[gat]
+impl<I : LendingIterator> LendingIterator for StepBy<I> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (self: &'_ mut StepBy<I>)
+      -> Option<Item<'_, I>>
+    {
+        let n = if self.first_take {
+            self.first_take = false;
+            0
+        } else {
+            self.step_minus_one
+        };
+        self.iter.nth(n)
+    }
+
+    fn size_hint (self: &'_ StepBy<I>)
+      -> (usize, Option<usize>)
+    {
+        let step = self.step_minus_one + 1;
+        let (lower, upper) = self.iter.size_hint();
+        let stepped_count = |len: usize| if self.first_take {
+            if len == 0 { 0 } else { 1 + (len - 1) / step }
+        } else {
+            len / step
+        };
+        (stepped_count(lower), upper.map(stepped_count))
+    }
+}
+
+impl<I> DoubleEndedLendingIterator
+    for StepBy<I>
+where
+    I : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back (self: &'_ mut StepBy<I>)
+      -> Option<Item<'_, I>>
+    {
+        // Skip the trailing items which `.next()` would never reach.
+        let rem = self.iter.len() % (self.step_minus_one + 1);
+        let n = if self.first_take {
+            if rem == 0 { self.step_minus_one } else { rem - 1 }
+        } else {
+            rem
+        };
+        self.iter.nth_back(n)
+    }
+}
+
+impl<I : ExactSizeLendingIterator> ExactSizeLendingIterator for StepBy<I> {}
+
+impl<I : FusedLendingIterator> FusedLendingIterator for StepBy<I> {}
@@ -46,4 +46,37 @@ impl<I : LendingIterator> LendingIterator for Take<I> {
             None
         }
     }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut Take<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        let step = count.min(self.remaining);
+        let missing = match self.iter.advance_by(step) {
+            | Ok(()) => 0,
+            | Err(missing) => missing.get(),
+        };
+        let advanced = step - missing;
+        self.remaining -= advanced;
+        NonZeroUsize::new(count - advanced).map_or(Ok(()), Err)
+    }
+
+    fn size_hint (self: &'_ Take<I>)
+      -> (usize, Option<usize>)
+    {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.min(self.remaining),
+            Some(upper.map_or(self.remaining, |upper| upper.min(self.remaining))),
+        )
+    }
 }
+
+impl<I : ExactSizeLendingIterator> ExactSizeLendingIterator for Take<I> {}
+
+impl<I : FusedLendingIterator> FusedLendingIterator for Take<I> {}
@@ -0,0 +1,129 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.take_while()`][LendingIterator::take_while()].
+pub
+struct TakeWhile<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    predicate: F,
+
+    pub(in crate)
+    done: bool,
+}
+
+#[gat]
+impl<I, F> LendingIterator
+    for TakeWhile<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut TakeWhile<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next()?;
+        if (self.predicate)(&item) {
+            Some(item)
+        } else {
+            self.done = true;
+            None
+        }
+    }
+
+    fn size_hint (self: &'_ TakeWhile<I, F>)
+      -> (usize, Option<usize>)
+    {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, F> FusedLendingIterator
+    for TakeWhile<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{}
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.take_until()`][LendingIterator::take_until()].
+pub
+struct TakeUntil<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    predicate: F,
+
+    pub(in crate)
+    done: bool,
+}
+
+#[gat]
+impl<I, F> LendingIterator
+    for TakeUntil<I, F>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut TakeUntil<I, F>,
+    ) -> Option<Item<'_, I>>
+    {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next()?;
+        self.done = (self.predicate)(&item);
+        Some(item)
+    }
+
+    fn size_hint (self: &'_ TakeUntil<I, F>)
+      -> (usize, Option<usize>)
+    {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lower, upper) = self.iter.size_hint();
+            (lower.min(1), upper)
+        }
+    }
+}
+
+impl<I, F> FusedLendingIterator
+    for TakeUntil<I, F>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> bool,
+{}
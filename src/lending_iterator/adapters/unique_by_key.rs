@@ -0,0 +1,80 @@
+use ::alloc::collections::BTreeSet;
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.unique_by_key()`][LendingIterator::unique_by_key()].
+pub
+struct UniqueByKey<I, F, K>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : Ord,
+{
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    key: F,
+
+    /// The keys of the items yielded so far.
+    pub(in crate)
+    seen: BTreeSet<K>,
+}
+
+#[gat]
+impl<I, F, K> LendingIterator
+    for UniqueByKey<I, F, K>
+where
+    I : LendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : Ord,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    fn next (
+        self: &'_ mut UniqueByKey<I, F, K>,
+    ) -> Option<Item<'_, I>>
+    {
+        let Self { iter, key, seen } = self;
+        iter.find(move |item| seen.insert(key(item)))
+    }
+
+    fn size_hint (self: &'_ UniqueByKey<I, F, K>)
+      -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.iter.size_hint();
+        if self.seen.is_empty() {
+            (lower.min(1), upper)
+        } else {
+            (0, upper)
+        }
+    }
+}
+
+impl<I, F, K> DoubleEndedLendingIterator
+    for UniqueByKey<I, F, K>
+where
+    I : DoubleEndedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : Ord,
+{
+    fn next_back (
+        self: &'_ mut UniqueByKey<I, F, K>,
+    ) -> Option<Item<'_, I>>
+    {
+        let Self { iter, key, seen } = self;
+        iter.rfind(move |item| seen.insert(key(item)))
+    }
+}
+
+impl<I, F, K> FusedLendingIterator
+    for UniqueByKey<I, F, K>
+where
+    I : FusedLendingIterator,
+    F : FnMut(&'_ Item<'_, I>) -> K,
+    K : Ord,
+{}
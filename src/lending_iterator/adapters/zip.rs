@@ -0,0 +1,257 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.zip()`][LendingIterator::zip()].
+pub
+struct Zip<I : LendingIterator, J : LendingIterator> {
+    pub(in crate)
+    first: I,
+
+    pub(in crate)
+    second: J,
+}
+
+#[gat]
+impl<I, J> LendingIterator
+    for Zip<I, J>
+where
+    I : LendingIterator,
+    J : LendingIterator,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        (Item<'next, I>, Item<'next, J>)
+    ;
+
+    fn next (
+        self: &'_ mut Zip<I, J>,
+    ) -> Option<(Item<'_, I>, Item<'_, J>)>
+    {
+        let fst = self.first.next()?;
+        let snd = self.second.next()?;
+        Some((fst, snd))
+    }
+
+    fn size_hint (self: &'_ Zip<I, J>)
+      -> (usize, Option<usize>)
+    {
+        let (lower_1, upper_1) = self.first.size_hint();
+        let (lower_2, upper_2) = self.second.size_hint();
+        let upper = match (upper_1, upper_2) {
+            | (Some(upper_1), Some(upper_2)) => Some(upper_1.min(upper_2)),
+            | (upper @ Some(_), None)
+            | (None, upper) => upper,
+        };
+        (lower_1.min(lower_2), upper)
+    }
+}
+
+/// Like for [`Iterator::zip()`], iterating from the back requires knowing the
+/// exact lengths of both iterators, so as to trim the excess of the longest
+/// one.
+impl<I, J> DoubleEndedLendingIterator
+    for Zip<I, J>
+where
+    I : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+    J : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back (
+        self: &'_ mut Zip<I, J>,
+    ) -> Option<(Item<'_, I>, Item<'_, J>)>
+    {
+        let (len_1, len_2) = (self.first.len(), self.second.len());
+        if len_1 > len_2 {
+            let _ = self.first.advance_back_by(len_1 - len_2);
+        } else if len_2 > len_1 {
+            let _ = self.second.advance_back_by(len_2 - len_1);
+        }
+        let fst = self.first.next_back()?;
+        let snd = self.second.next_back()?;
+        Some((fst, snd))
+    }
+}
+
+impl<I, J> ExactSizeLendingIterator
+    for Zip<I, J>
+where
+    I : ExactSizeLendingIterator,
+    J : ExactSizeLendingIterator,
+{}
+
+impl<I, J> FusedLendingIterator
+    for Zip<I, J>
+where
+    I : FusedLendingIterator,
+    J : FusedLendingIterator,
+{}
+
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.zip_longest()`][LendingIterator::zip_longest()].
+pub
+struct ZipLongest<I : LendingIterator, J : LendingIterator> {
+    pub(in crate)
+    first: Fuse<I>,
+
+    pub(in crate)
+    second: Fuse<J>,
+}
+
+#[gat]
+impl<I, J> LendingIterator
+    for ZipLongest<I, J>
+where
+    I : LendingIterator,
+    J : LendingIterator,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        EitherOrBoth<Item<'next, I>, Item<'next, J>>
+    ;
+
+    fn next (
+        self: &'_ mut ZipLongest<I, J>,
+    ) -> Option<EitherOrBoth<Item<'_, I>, Item<'_, J>>>
+    {
+        match (self.first.next(), self.second.next()) {
+            | (Some(fst), Some(snd)) => Some(EitherOrBoth::Both(fst, snd)),
+            | (Some(fst), None) => Some(EitherOrBoth::Left(fst)),
+            | (None, Some(snd)) => Some(EitherOrBoth::Right(snd)),
+            | (None, None) => None,
+        }
+    }
+
+    fn size_hint (self: &'_ ZipLongest<I, J>)
+      -> (usize, Option<usize>)
+    {
+        let (lower_1, upper_1) = self.first.size_hint();
+        let (lower_2, upper_2) = self.second.size_hint();
+        let upper = match (upper_1, upper_2) {
+            | (Some(upper_1), Some(upper_2)) => Some(upper_1.max(upper_2)),
+            | _ => None,
+        };
+        (lower_1.max(lower_2), upper)
+    }
+}
+
+/// Iterating from the back requires knowing the exact lengths of both
+/// iterators, so as to yield the excess of the longest one first.
+impl<I, J> DoubleEndedLendingIterator
+    for ZipLongest<I, J>
+where
+    I : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+    J : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back (
+        self: &'_ mut ZipLongest<I, J>,
+    ) -> Option<EitherOrBoth<Item<'_, I>, Item<'_, J>>>
+    {
+        let (len_1, len_2) = (self.first.len(), self.second.len());
+        Some(if len_1 > len_2 {
+            EitherOrBoth::Left(self.first.next_back()?)
+        } else if len_2 > len_1 {
+            EitherOrBoth::Right(self.second.next_back()?)
+        } else {
+            let fst = self.first.next_back()?;
+            let snd = self.second.next_back()?;
+            EitherOrBoth::Both(fst, snd)
+        })
+    }
+}
+
+impl<I, J> ExactSizeLendingIterator
+    for ZipLongest<I, J>
+where
+    I : ExactSizeLendingIterator,
+    J : ExactSizeLendingIterator,
+{}
+
+impl<I, J> FusedLendingIterator
+    for ZipLongest<I, J>
+where
+    I : LendingIterator,
+    J : LendingIterator,
+{}
+
+/// The item type of [`.zip_longest()`][LendingIterator::zip_longest()]:
+/// either the items of both iterators, or the one of the iterator that has not
+/// been exhausted yet.
+#[derive(
+    Debug,
+    Clone, Copy,
+    PartialEq, Eq,
+    PartialOrd, Ord,
+    Hash,
+)]
+pub
+enum EitherOrBoth<L, R> {
+    /// Both iterators yielded an item.
+    Both(L, R),
+    /// Only the first (_left_) iterator yielded an item.
+    Left(L),
+    /// Only the second (_right_) iterator yielded an item.
+    Right(R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// The item from the first iterator, if any.
+    pub
+    fn left (self: EitherOrBoth<L, R>)
+      -> Option<L>
+    {
+        match self {
+            | Self::Both(left, _) | Self::Left(left) => Some(left),
+            | Self::Right(_) => None,
+        }
+    }
+
+    /// The item from the second iterator, if any.
+    pub
+    fn right (self: EitherOrBoth<L, R>)
+      -> Option<R>
+    {
+        match self {
+            | Self::Both(_, right) | Self::Right(right) => Some(right),
+            | Self::Left(_) => None,
+        }
+    }
+
+    /// The items of both iterators, if both yielded.
+    pub
+    fn both (self: EitherOrBoth<L, R>)
+      -> Option<(L, R)>
+    {
+        match self {
+            | Self::Both(left, right) => Some((left, right)),
+            | _ => None,
+        }
+    }
+
+    /// Converts from `&EitherOrBoth<L, R>` to `EitherOrBoth<&L, &R>`.
+    pub
+    fn as_ref (self: &'_ EitherOrBoth<L, R>)
+      -> EitherOrBoth<&'_ L, &'_ R>
+    {
+        match *self {
+            | Self::Both(ref left, ref right) => EitherOrBoth::Both(left, right),
+            | Self::Left(ref left) => EitherOrBoth::Left(left),
+            | Self::Right(ref right) => EitherOrBoth::Right(right),
+        }
+    }
+
+    /// Converts from `&mut EitherOrBoth<L, R>` to
+    /// `EitherOrBoth<&mut L, &mut R>`.
+    pub
+    fn as_mut (self: &'_ mut EitherOrBoth<L, R>)
+      -> EitherOrBoth<&'_ mut L, &'_ mut R>
+    {
+        match *self {
+            | Self::Both(ref mut left, ref mut right) => {
+                EitherOrBoth::Both(left, right)
+            },
+            | Self::Left(ref mut left) => EitherOrBoth::Left(left),
+            | Self::Right(ref mut right) => EitherOrBoth::Right(right),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+/// The <code>impl [LendingIterator]</code> returned by
+/// [`.zip_iter()`][LendingIterator::zip_iter()].
+pub
+struct ZipIter<I : LendingIterator, J : Iterator> {
+    pub(in crate)
+    iter: I,
+
+    pub(in crate)
+    values: J,
+}
+
+#[gat]
+impl<I : LendingIterator, J : Iterator> LendingIterator for ZipIter<I, J> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        (J::Item, Item<'next, I>)
+    ;
+
+    fn next (self: &'_ mut ZipIter<I, J>)
+      -> Option<(J::Item, Item<'_, I>)>
+    {
+        let value = self.values.next()?;
+        let item = self.iter.next()?;
+        Some((value, item))
+    }
+
+    #[inline]
+    fn nth (
+        self: &'_ mut ZipIter<I, J>,
+        n: usize,
+    ) -> Option<(J::Item, Item<'_, I>)>
+    {
+        // Advance both sides, even when `values` runs out first.
+        let value = self.values.nth(n);
+        let item = self.iter.nth(n)?;
+        Some((value?, item))
+    }
+
+    fn size_hint (self: &'_ ZipIter<I, J>)
+      -> (usize, Option<usize>)
+    {
+        let (lower_1, upper_1) = self.values.size_hint();
+        let (lower_2, upper_2) = self.iter.size_hint();
+        let upper = match (upper_1, upper_2) {
+            | (Some(upper_1), Some(upper_2)) => Some(upper_1.min(upper_2)),
+            | (upper @ Some(_), None)
+            | (None, upper) => upper,
+        };
+        (lower_1.min(lower_2), upper)
+    }
+}
+
+/// Like for [`Iterator::zip()`], iterating from the back requires knowing the
+/// exact lengths of both iterators, so as to trim the excess of the longest
+/// one.
+impl<I, J> DoubleEndedLendingIterator
+    for ZipIter<I, J>
+where
+    I : DoubleEndedLendingIterator + ExactSizeLendingIterator,
+    J : DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back (self: &'_ mut ZipIter<I, J>)
+      -> Option<(J::Item, Item<'_, I>)>
+    {
+        let (len_1, len_2) = (self.values.len(), self.iter.len());
+        if len_1 > len_2 {
+            self.values.nth_back(len_1 - len_2 - 1);
+        } else if len_2 > len_1 {
+            let _ = self.iter.advance_back_by(len_2 - len_1);
+        }
+        let value = self.values.next_back()?;
+        let item = self.iter.next_back()?;
+        Some((value, item))
+    }
+}
+
+impl<I, J> ExactSizeLendingIterator
+    for ZipIter<I, J>
+where
+    I : ExactSizeLendingIterator,
+    J : ExactSizeIterator,
+{}
+
+impl<I, J> FusedLendingIterator
+    for ZipIter<I, J>
+where
+    I : FusedLendingIterator,
+    J : ::core::iter::FusedIterator,
+{}
@@ -0,0 +1,199 @@
+//! [`FromLendingIterator`] and [`ExtendLending`], along with their impls.
+
+use super::*;
+
+/// [`LendingIterator`] counterpart of [`FromIterator`]: construction of a
+/// collection out of the `Item`s lent by a [`LendingIterator`], where `H` is
+/// the [`HKT`][trait@HKT] of such items.
+///
+/// Since lent items cannot coëxist, this is mostly useful for collections of
+/// owned values obtained from the lent ones (_e.g._, a `String` out of lent
+/// `&str`s, or a `Vec<T>` out of lent `&[T]`s).
+///
+/// Used by [`.collect_into()`][LendingIterator::collect_into()].
+pub
+trait FromLendingIterator<H : HKT> : Sized {
+    /// Creates a value from the items of a [`LendingIterator`].
+    fn from_lending_iter<I> (
+        iter: I,
+    ) -> Self
+    where
+        I : LendingIterator,
+        I : for<'any> LendingIteratorඞItem<'any, T = Feed<'any, H>>,
+    ;
+}
+
+/// [`LendingIterator`] counterpart of [`Extend`]: extension of a collection
+/// with the `Item`s lent by a [`LendingIterator`], where `H` is the
+/// [`HKT`][trait@HKT] of such items.
+///
+/// See [`FromLendingIterator`] for more info.
+///
+/// Mainly used through the [`.extend_from_lending()`] extension method.
+///
+/// [`.extend_from_lending()`]: trait@extend_from_lending
+pub
+trait ExtendLending<H : HKT> {
+    /// Extends `self` with the items of a [`LendingIterator`].
+    fn extend_lending<I> (
+        &mut self,
+        iter: I,
+    )
+    where
+        I : LendingIterator,
+        I : for<'any> LendingIteratorඞItem<'any, T = Feed<'any, H>>,
+    ;
+}
+
+/// Extension trait based convenience method version of
+/// [`ExtendLending::extend_lending()`], which does not require `H` to be
+/// inferred.
+#[extension(pub trait extend_from_lending)]
+impl<C : ?Sized> C {
+    /// Extends `self` with the items of a [`LendingIterator`].
+    ///
+    /// ## Example
+    ///
+    /**  - ```rust
+        use ::lending_iterator::prelude::*;
+
+        let mut array = *b"lending";
+        let mut chunks = array.windows_mut::<3>().step_by(3);
+        let mut bytes = b"Chunks: ".to_vec();
+        bytes.extend_from_lending(&mut chunks);
+        assert_eq!(bytes, b"Chunks: lendin");
+        ``` */
+    fn extend_from_lending<I> (
+        &mut self,
+        iter: I,
+    )
+    where
+        I : ඞExtending<C>,
+    {
+        iter.ඞextend(self)
+    }
+}
+
+/// Helper trait to express the `C : ExtendLending<HKTItem<I>>` bound of
+/// [`.extend_from_lending()`] with `I` as the `Self` type: otherwise, Rust
+/// could eagerly (and thus wrongly) reject the bound before `I` gets
+/// inferred.
+///
+/// [`.extend_from_lending()`]: trait@extend_from_lending
+#[doc(hidden)] /** Not part of the public API */ pub
+trait ඞExtending<C : ?Sized> : LendingIterator {
+    fn ඞextend (self, collection: &'_ mut C)
+    ;
+}
+
+impl<I : LendingIterator, C : ?Sized> ඞExtending<C> for I
+where
+    C : ExtendLending<HKTItem<I>>,
+{
+    fn ඞextend (self: I, collection: &'_ mut C)
+    {
+        ExtendLending::<HKTItem<I>>::extend_lending(collection, self)
+    }
+}
+
+macro_rules! impls {(
+    $(
+        $(#[$attrs:meta])*
+        [$($generics:tt)*] $Collection:ty : $H:ty
+        = |$collection:ident, $item:ident| $extend:expr;
+    )*
+) => (
+    $(
+        $(#[$attrs])*
+        impl<$($generics)*>
+            ExtendLending<$H>
+        for
+            $Collection
+        {
+            fn extend_lending<I> (
+                self: &'_ mut $Collection,
+                iter: I,
+            )
+            where
+                I : LendingIterator,
+                I : for<'any> LendingIteratorඞItem<'any, T = Feed<'any, $H>>,
+            {
+                let $collection = self;
+                iter.for_each(|$item| $extend);
+            }
+        }
+
+        $(#[$attrs])*
+        impl<$($generics)*>
+            FromLendingIterator<$H>
+        for
+            $Collection
+        {
+            fn from_lending_iter<I> (
+                iter: I,
+            ) -> $Collection
+            where
+                I : LendingIterator,
+                I : for<'any> LendingIteratorඞItem<'any, T = Feed<'any, $H>>,
+            {
+                let mut collection = <$Collection>::default();
+                ExtendLending::<$H>::extend_lending(&mut collection, iter);
+                collection
+            }
+        }
+    )*
+)}
+
+macro_rules! impls_for_collections_of_clones {(
+    $(
+        $Collection:ident <T $(: $Bound:ident)?>
+    ),* $(,)?
+) => (
+    impls! {
+        $(
+            #[apply(cfg_alloc)]
+            [T : Clone $(+ $Bound)?]
+            ::alloc::collections::$Collection<T> : HKT!(&T)
+            = |collection, elem| collection.extend(Some(T::clone(elem)));
+
+            #[apply(cfg_alloc)]
+            [T : Clone $(+ $Bound)?]
+            ::alloc::collections::$Collection<T> : HKT!(&[T])
+            = |collection, slice| collection.extend(slice.iter().cloned());
+
+            #[apply(cfg_alloc)]
+            [T : Clone $(+ $Bound)?, const N : usize]
+            ::alloc::collections::$Collection<T> : HKT!(&mut [T; N])
+            = |collection, array| collection.extend(array.iter().cloned());
+        )*
+    }
+)}
+
+impls! {
+    #[apply(cfg_alloc)]
+    [] ::alloc::string::String : HKT!(&str)
+    = |string, s| string.push_str(s);
+
+    #[apply(cfg_alloc)]
+    [] ::alloc::string::String : HKT!(char)
+    = |string, c| string.push(c);
+
+    #[apply(cfg_alloc)]
+    [T : Clone] ::alloc::vec::Vec<T> : HKT!(&T)
+    = |vec, elem| vec.push(T::clone(elem));
+
+    #[apply(cfg_alloc)]
+    [T : Clone] ::alloc::vec::Vec<T> : HKT!(&[T])
+    = |vec, slice| vec.extend_from_slice(slice);
+
+    #[apply(cfg_alloc)]
+    [T : Clone, const N : usize] ::alloc::vec::Vec<T> : HKT!(&mut [T; N])
+    = |vec, array| vec.extend_from_slice(&array[..]);
+}
+
+impls_for_collections_of_clones! {
+    BinaryHeap<T : Ord>,
+    BTreeSet<T : Ord>,
+    LinkedList<T>,
+    VecDeque<T>,
+}
@@ -5,6 +5,10 @@ match_! {(
     from_iter,
     #[apply(cfg_futures)]
     from_stream,
+    #[apply(cfg_alloc)]
+    interleave,
+    #[apply(cfg_alloc)]
+    kmerge_by,
     repeat_mut,
     windows_mut_,
 ) {(
@@ -44,7 +44,7 @@
 ///
 /// It can also be viewed as a convenience layer over:
 ///
-/// <code>::lending_iterator::[repeat_mut]\(state\)<br/>    [.filter_map]::\<Item, _\>\(move |\[\], it| next\(it\)\)</code>
+/// <code>::lending_iterator::[repeat_mut]\(state\)<br/>    [.map_while]::\<Item, _\>\(move |\[\], it| next\(it\)\)</code>
 ///
 /**  - ```rust
     use ::lending_iterator::prelude::*;
@@ -61,7 +61,7 @@
      */
     {
         lending_iterator::repeat_mut(person)
-            .filter_map::<HKT!(&str), _>(
+            .map_while::<HKT!(&str), _>(
                 |[], p| if p.age > 0 {
                     Some(&p.name)
                 } else {
@@ -72,7 +72,7 @@
     ``` */
 ///
 /// [repeat_mut]: crate::repeat_mut()
-/// [.filter_map]: crate::LendingIterator::filter_map
+/// [.map_while]: crate::LendingIterator::map_while
 ///
 /// ### `FromFn`
 ///
@@ -54,4 +54,79 @@ for
     {
         self.0.next()
     }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut FromIter<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        match self.0.size_hint() {
+            // Exact size: we can afford to defer to the (often O(1)) `.nth()`,
+            // without losing track of the number of missing steps.
+            | (lo, Some(hi)) if lo == hi => {
+                if let Some(n) = count.checked_sub(1) {
+                    self.0.nth(n);
+                }
+                NonZeroUsize::new(count.saturating_sub(lo)).map_or(Ok(()), Err)
+            },
+            | _ => {
+                for i in 0 .. count {
+                    if self.0.next().is_none() {
+                        return Err(NonZeroUsize::new(count - i).unwrap());
+                    }
+                }
+                Ok(())
+            },
+        }
+    }
+
+    #[inline]
+    fn nth (
+        self: &'_ mut FromIter<I>,
+        n: usize,
+    ) -> Option<I::Item>
+    {
+        self.0.nth(n)
+    }
+
+    fn size_hint (self: &'_ FromIter<I>)
+      -> (usize, Option<usize>)
+    {
+        self.0.size_hint()
+    }
+}
+
+impl<I : ?Sized + DoubleEndedIterator>
+    DoubleEndedLendingIterator
+for
+    FromIter<I>
+{
+    fn next_back (
+        self: &'_ mut FromIter<I>,
+    ) -> Option<I::Item>
+    {
+        self.0.next_back()
+    }
+
+    #[inline]
+    fn nth_back (
+        self: &'_ mut FromIter<I>,
+        n: usize,
+    ) -> Option<I::Item>
+    {
+        self.0.nth_back(n)
+    }
 }
+
+impl<I : ?Sized + ExactSizeIterator>
+    ExactSizeLendingIterator
+for
+    FromIter<I>
+{}
+
+impl<I : ?Sized + ::core::iter::FusedIterator>
+    FusedLendingIterator
+for
+    FromIter<I>
+{}
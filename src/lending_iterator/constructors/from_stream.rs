@@ -138,4 +138,13 @@ for
     {
         Some(self.0.next())
     }
+
+    fn size_hint (self: &'_ FromStream<S>)
+      -> (usize, Option<usize>)
+    {
+        (usize::MAX, None)
+    }
 }
+
+#[apply(cfg_futures)]
+impl<S : Stream + Unpin> FusedLendingIterator for FromStream<S> {}
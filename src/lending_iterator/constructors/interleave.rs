@@ -0,0 +1,172 @@
+use ::alloc::vec::Vec;
+
+/// Round-robin over several [`LendingIterator`]s, lending one item of each in
+/// turn.
+///
+/// The sources are dropped as they get exhausted, and the iteration goes on
+/// with the remaining ones, until all of them are exhausted: see
+/// [`interleave_shortest()`] to stop as soon as one of them is.
+///
+/// In order to interleave heterogeneous iterators, they can be
+/// [`.dyn_boxed()`][LendingIterator::dyn_boxed()] into a common
+/// <code>[Box]\<dyn [LendingIteratorDyn]\<Item = …\> + …\></code> type.
+///
+/// [Box]: ::alloc::boxed::Box
+///
+/// ## Example
+///
+/**  - ```rust
+    use ::lending_iterator::prelude::*;
+
+    type Source<'r> = Box<dyn 'r + LendingIteratorDyn<Item = HKT!(&mut u8)>>;
+
+    let (mut a, mut b) = ([0; 3], [0; 4]);
+    let sources: [Source<'_>; 3] = [
+        a.windows_mut::<1>().map_to_mut(|[], [x]| x).dyn_boxed(),
+        lending_iterator::repeat_mut(42).take(2).dyn_boxed(),
+        b.windows_mut::<2>().map_to_mut(|[], [_, x]| x).dyn_boxed(),
+    ];
+    let mut order = 0;
+    let mut iter = lending_iterator::interleave(sources);
+    while let Some(x) = iter.next() {
+        order += 1;
+        *x = order;
+    }
+    drop(iter);
+    assert_eq!(a, [1, 4, 7]);
+    assert_eq!(b, [0, 3, 6, 8]);
+    ``` */
+pub
+fn interleave<I : LendingIterator> (
+    sources: impl IntoIterator<Item = I>,
+) -> Interleave<I>
+{
+    interleave_weighted(sources.into_iter().map(|source| (1, source)))
+}
+
+/// Like [`interleave()`], but stopping as soon as one of the sources is
+/// exhausted.
+pub
+fn interleave_shortest<I : LendingIterator> (
+    sources: impl IntoIterator<Item = I>,
+) -> Interleave<I>
+{
+    Interleave {
+        shortest: true,
+        ..interleave(sources)
+    }
+}
+
+/// Weighted round-robin: like [`interleave()`], but lending up to `weight`
+/// consecutive items of each `(weight, source)` in turn.
+///
+/// Sources with a `weight` of `0` are ignored.
+///
+/// ## Example
+///
+/**  - ```rust
+    use ::lending_iterator::prelude::*;
+
+    let mut iter = lending_iterator::interleave_weighted([
+        (2, lending_iterator::from_iter(0 .. 5)),
+        (1, lending_iterator::from_iter(10 .. 12)),
+    ]);
+    let mut items = vec![];
+    while let Some(item) = iter.next() {
+        items.push(item);
+    }
+    assert_eq!(items, [0, 1, 10, 2, 3, 11, 4]);
+    ``` */
+pub
+fn interleave_weighted<I : LendingIterator> (
+    sources: impl IntoIterator<Item = (usize, I)>,
+) -> Interleave<I>
+{
+    Interleave {
+        sources:
+            sources
+                .into_iter()
+                .filter(|&(weight, _)| weight != 0)
+                .map(|(weight, source)| (source, weight))
+                .collect()
+        ,
+        current: 0,
+        yielded: 0,
+        shortest: false,
+    }
+}
+
+/// The <code>impl [LendingIterator]</code> returned by [`interleave()`],
+/// [`interleave_shortest()`] and [`interleave_weighted()`].
+pub
+struct Interleave<I : LendingIterator> {
+    /// The non-exhausted sources, along with their weight.
+    sources: Vec<(I, usize)>,
+    /// Index of the source currently being lent from.
+    current: usize,
+    /// Number of items lent from the current source, in this round.
+    yielded: usize,
+    shortest: bool,
+}
+
+#[gat]
+impl<I : LendingIterator> LendingIterator for Interleave<I> {
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        Item<'next, I>
+    ;
+
+    const ඞIS_FUSED: bool = true;
+
+    fn next (self: &'_ mut Interleave<I>)
+      -> Option<Item<'_, I>>
+    {
+        let mut this = self;
+        loop {
+            if this.sources.is_empty() {
+                return None;
+            }
+            if this.yielded == this.sources[this.current].1 {
+                this.yielded = 0;
+                this.current = (this.current + 1) % this.sources.len();
+            }
+            this.yielded += 1;
+            polonius!(|this| -> Option<Item<'polonius, I>> {
+                let current = this.current;
+                if let item @ Some(_) = this.sources[current].0.next() {
+                    polonius_return!(item);
+                }
+            });
+            // The current source is exhausted.
+            if this.shortest {
+                this.sources.clear();
+                return None;
+            }
+            this.sources.remove(this.current);
+            this.yielded = 0;
+            if this.current == this.sources.len() {
+                this.current = 0;
+            }
+        }
+    }
+
+    fn size_hint (self: &'_ Interleave<I>)
+      -> (usize, Option<usize>)
+    {
+        let mut lower = 0_usize;
+        let mut upper = Some(0_usize);
+        for (source, _) in &self.sources {
+            let (source_lower, source_upper) = source.size_hint();
+            lower = lower.saturating_add(source_lower);
+            upper = upper.and_then(|upper| upper.checked_add(source_upper?));
+        }
+        if self.shortest {
+            lower = 0;
+        }
+        (lower, upper)
+    }
+}
+
+impl<I : LendingIterator> FusedLendingIterator for Interleave<I> {}
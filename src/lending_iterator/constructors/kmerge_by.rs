@@ -0,0 +1,167 @@
+use ::alloc::vec::Vec;
+use ::core::cmp::Ordering;
+
+/// Merges several sorted [`LendingIterator`]s into a sorted one, **provided
+/// their `Item<'_>` does not depend on `'_`** (_e.g._, after a
+/// [`.to_owned_items()`][LendingIterator::to_owned_items()]).
+///
+/// The heads of the sources are kept in a binary heap, ordered by the `cmp`
+/// closure, and on [`Ordering::Equal`] the head of the source which comes
+/// first in `sources` is yielded first. Note that the head of each source is
+/// pulled right away.
+///
+/// In order to merge heterogeneous iterators, they can be
+/// [`.dyn_boxed()`][LendingIterator::dyn_boxed()] into a common
+/// <code>[Box]\<dyn [LendingIteratorDyn]\<Item = …\> + …\></code> type.
+///
+/// See [`.merge_by()`][LendingIterator::merge_by()] to merge two iterators
+/// with different item types.
+///
+/// [Box]: ::alloc::boxed::Box
+///
+/// ## Example
+///
+/**  - ```rust
+    use ::lending_iterator::prelude::*;
+
+    type Source<'r> = Box<dyn 'r + LendingIteratorDyn<Item = HKT!(u32)>>;
+
+    let sources: [Source<'_>; 3] = [
+        lending_iterator::from_iter([1, 4, 9]).dyn_boxed(),
+        lending_iterator::from_iter(0 ..).step_by(3).take(4).dyn_boxed(),
+        lending_iterator::from_fn::<HKT!(u32), _, _>(1, |n| {
+            *n *= 2;
+            Some(*n).filter(|&n| n < 10)
+        })
+        .dyn_boxed(),
+    ];
+    let mut iter = lending_iterator::kmerge_by(sources, |x, y| x.cmp(y));
+    let mut items = vec![];
+    while let Some(item) = iter.next() {
+        items.push(item);
+    }
+    assert_eq!(items, [0, 1, 2, 3, 4, 4, 6, 8, 9, 9]);
+    ``` */
+pub
+fn kmerge_by<I, T, F> (
+    sources: impl IntoIterator<Item = I>,
+    cmp: F,
+) -> KMergeBy<I, T, F>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+    F : FnMut(&T, &T) -> Ordering,
+{
+    let mut this = KMergeBy {
+        heap: Vec::new(),
+        cmp,
+    };
+    for (idx, mut source) in sources.into_iter().enumerate() {
+        if let Some(head) = source.next() {
+            this.heap.push((head, idx, source));
+            this.sift_up(this.heap.len() - 1);
+        }
+    }
+    this
+}
+
+/// The <code>impl [LendingIterator]</code> returned by [`kmerge_by()`].
+pub
+struct KMergeBy<I : LendingIterator, T, F> {
+    /// Min-heap of the non-exhausted sources, along with their head and their
+    /// index in the original `sources` (to break ties).
+    heap: Vec<(T, usize, I)>,
+    cmp: F,
+}
+
+impl<I, T, F> KMergeBy<I, T, F>
+where
+    I : LendingIterator,
+    F : FnMut(&T, &T) -> Ordering,
+{
+    fn less (self: &'_ mut KMergeBy<I, T, F>, i: usize, j: usize)
+      -> bool
+    {
+        let ((a, a_idx, _), (b, b_idx, _)) = (&self.heap[i], &self.heap[j]);
+        (self.cmp)(a, b).then(a_idx.cmp(b_idx)) == Ordering::Less
+    }
+
+    fn sift_up (self: &'_ mut KMergeBy<I, T, F>, mut i: usize)
+    {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down (self: &'_ mut KMergeBy<I, T, F>, mut i: usize)
+    {
+        loop {
+            let mut smallest = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.heap.len() && self.less(child, smallest) {
+                    smallest = child;
+                }
+            }
+            if smallest == i {
+                break;
+            }
+            self.heap.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+#[gat]
+impl<I, T, F> LendingIterator for KMergeBy<I, T, F>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+    F : FnMut(&T, &T) -> Ordering,
+{
+    type Item<'next>
+    where
+        Self : 'next,
+    =
+        T
+    ;
+
+    const ඞIS_FUSED: bool = true;
+
+    fn next (self: &'_ mut KMergeBy<I, T, F>)
+      -> Option<T>
+    {
+        let (head, _, source) = self.heap.first_mut()?;
+        let head = match source.next() {
+            | Some(next) => ::core::mem::replace(head, next),
+            | None => self.heap.swap_remove(0).0,
+        };
+        if self.heap.is_empty().not() {
+            self.sift_down(0);
+        }
+        Some(head)
+    }
+
+    fn size_hint (self: &'_ KMergeBy<I, T, F>)
+      -> (usize, Option<usize>)
+    {
+        let mut lower = 0_usize;
+        let mut upper = Some(0_usize);
+        for (_, _, source) in &self.heap {
+            let (source_lower, source_upper) = source.size_hint();
+            lower = lower.saturating_add(source_lower).saturating_add(1);
+            upper = upper.and_then(|upper| {
+                upper.checked_add(source_upper?)?.checked_add(1)
+            });
+        }
+        (lower, upper)
+    }
+}
+
+impl<I, T, F> FusedLendingIterator for KMergeBy<I, T, F>
+where
+    I : LendingIterator + for<'any> LendingIteratorඞItem<'any, T = T>,
+    F : FnMut(&T, &T) -> Ordering,
+{}
@@ -62,10 +62,28 @@ for
         &'next mut State
     ;
 
+    const ඞIS_FUSED: bool = true;
+
     fn next (
         self: &'_ mut RepeatMut<State>,
     ) -> Option<&'_ mut State>
     {
         Some(&mut self.0)
     }
+
+    fn size_hint (self: &'_ RepeatMut<State>)
+      -> (usize, Option<usize>)
+    {
+        (usize::MAX, None)
+    }
+}
+
+impl<State> FusedLendingIterator for RepeatMut<State> {}
+
+/// Restarting a [`RepeatMut`] is a no-op, since it never gets exhausted.
+impl<State> Restartable for RepeatMut<State> {
+    fn restart (
+        self: &'_ mut RepeatMut<State>,
+    )
+    {}
 }
@@ -25,6 +25,7 @@ fn windows_mut<T, const WINDOW_SIZE: usize> (
 ) -> WindowsMut<&mut [T], WINDOW_SIZE>
 {
     WindowsMut {
+        end: window_count(slice.len(), WINDOW_SIZE),
         slice,
         start: 0,
     }
@@ -34,7 +35,18 @@ fn windows_mut<T, const WINDOW_SIZE: usize> (
 pub
 struct WindowsMut<Slice, const WINDOW_SIZE: usize> {
     slice: Slice,
+    /// Index of the next window to be yielded from the front.
     start: usize,
+    /// One past the index of the next window to be yielded from the back.
+    end: usize,
+}
+
+/// Number of windows of the given size fitting in `len` elements.
+fn window_count (len: usize, window_size: usize)
+  -> usize
+{
+    len .checked_sub(window_size)
+        .map_or(0, |extra| extra.saturating_add(1))
 }
 
 #[gat]
@@ -50,26 +62,107 @@ for
         &'next mut [T; WINDOW_SIZE]
     ;
 
+    const ඞIS_FUSED: bool = true;
+
     fn next<'next> (
         self: &'next mut WindowsMut<&'lt mut [T], WINDOW_SIZE>,
     ) -> Option<&'next mut [T;  WINDOW_SIZE]>
     {
-        self.nth(0)
+        if self.start >= self.end {
+            return None;
+        }
+        let window = &mut self.slice[self.start ..][.. WINDOW_SIZE];
+        self.start += 1;
+        Some(window.try_into().unwrap())
     }
 
     #[inline]
-    fn nth<'nth> (
-        self: &'nth mut WindowsMut<&'lt mut [T], WINDOW_SIZE>,
-        n: usize,
-    ) -> Option<&'nth mut [T;  WINDOW_SIZE]>
+    fn advance_by (
+        self: &'_ mut WindowsMut<&'lt mut [T], WINDOW_SIZE>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        let step = count.min(self.remaining());
+        self.start += step;
+        NonZeroUsize::new(count - step).map_or(Ok(()), Err)
+    }
+
+    fn size_hint (self: &'_ WindowsMut<&'lt mut [T], WINDOW_SIZE>)
+      -> (usize, Option<usize>)
+    {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    fn count (self: WindowsMut<&'lt mut [T], WINDOW_SIZE>)
+      -> usize
+    {
+        self.remaining()
+    }
+}
+
+impl<'lt, T, const WINDOW_SIZE: usize>
+    DoubleEndedLendingIterator
+for
+    WindowsMut<&'lt mut [T], WINDOW_SIZE>
+{
+    fn next_back<'next> (
+        self: &'next mut WindowsMut<&'lt mut [T], WINDOW_SIZE>,
+    ) -> Option<&'next mut [T;  WINDOW_SIZE]>
+    {
+        if self.start >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        let window = &mut self.slice[self.end ..][.. WINDOW_SIZE];
+        Some(window.try_into().unwrap())
+    }
+
+    #[inline]
+    fn advance_back_by (
+        self: &'_ mut WindowsMut<&'lt mut [T], WINDOW_SIZE>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        let step = count.min(self.remaining());
+        self.end -= step;
+        NonZeroUsize::new(count - step).map_or(Ok(()), Err)
+    }
+}
+
+impl<T, const WINDOW_SIZE: usize>
+    ExactSizeLendingIterator
+for
+    WindowsMut<&'_ mut [T], WINDOW_SIZE>
+{}
+
+impl<T, const WINDOW_SIZE: usize>
+    FusedLendingIterator
+for
+    WindowsMut<&'_ mut [T], WINDOW_SIZE>
+{}
+
+impl<'lt, T, const WINDOW_SIZE: usize>
+    Restartable
+for
+    WindowsMut<&'lt mut [T], WINDOW_SIZE>
+{
+    fn restart (
+        self: &'_ mut WindowsMut<&'lt mut [T], WINDOW_SIZE>,
+    )
+    {
+        self.start = 0;
+        self.end = window_count(self.slice.len(), WINDOW_SIZE);
+    }
+}
+
+impl<'lt, T, const WINDOW_SIZE: usize>
+    WindowsMut<&'lt mut [T], WINDOW_SIZE>
+{
+    /// Number of windows yet to be yielded.
+    fn remaining (self: &'_ WindowsMut<&'lt mut [T], WINDOW_SIZE>)
+      -> usize
     {
-        let new_start = self.start.checked_add(n)?;
-        let slice =
-            self.slice
-                .get_mut(new_start ..)?
-                .get_mut(.. WINDOW_SIZE)?
-        ;
-        self.start = new_start + 1;
-        Some(slice.try_into().unwrap())
+        self.end - self.start
     }
 }
@@ -0,0 +1,836 @@
+//! [`Consumer`]s: reusable terminal operations, to be fed the items of a
+//! single [`LendingIterator`] pass through
+//! [`.fold_many()`][LendingIterator::fold_many()].
+//!
+//! Since lent items can be neither cloned nor buffered, performing several
+//! terminal operations over the same pass (_e.g._, a sum, a histogram, and a
+//! checksum of the `windows_mut()` of some buffer) requires feeding each item,
+//! in turn, to each of these operations. A tuple of [`Consumer`]s does exactly
+//! that, and is itself a [`Consumer`] of the tuple of their outputs.
+//!
+//! [`.fold_many()`][LendingIterator::fold_many()] hands the [`Consumers`]
+//! constructors for the items of the iterator to a closure, which thus
+//! infers the [`HKT`][trait@HKT] `Item` of the lent items, and with it, the
+//! type of the closure parameters. Outside of it, the free constructors taking
+//! a closure need to be turbofished that `Item` (much like
+//! [`from_fn()`][crate::from_fn()] does).
+//!
+//! # Example
+//!
+/*!  - ```rust
+    use ::lending_iterator::{consumers, prelude::*};
+
+    let mut array = *b"abcab";
+    let (count, checksum, histogram) =
+        array
+            .windows_mut::<2>()
+            .fold_many(|c| (
+                c.count(),
+                c.fold(0, |acc, &&mut [a, b]| acc ^ a ^ b),
+                c.counts_by(|&&mut pair| pair),
+            ))
+    ;
+    assert_eq!(count, 4);
+    assert_eq!(checksum, b'a' ^ b'b');
+    assert_eq!(histogram[b"ab"], 2);
+    assert_eq!(histogram[b"ca"], 1);
+
+    // Same thing, with the free constructors.
+    type Window = HKT!(&mut [u8; 2]);
+    let (count, checksum) =
+        array
+            .windows_mut::<2>()
+            .fold_many(|_| (
+                consumers::count(),
+                consumers::fold::<Window, _, _>(0, |acc, &&mut [a, b]| {
+                    acc ^ a ^ b
+                }),
+            ))
+    ;
+    assert_eq!((count, checksum), (4, b'a' ^ b'b'));
+    ``` */
+
+use {
+    ::core::ops::ControlFlow,
+    super::*,
+};
+
+/// An operation to be fed, by shared reference, the items of a
+/// [`LendingIterator`] lending [`Feed`]`<'_, Item>`s, before yielding some
+/// final [`Self::Output`][Consumer::Output].
+///
+/// See [the module-level documentation][self] for more info.
+pub
+trait Consumer<Item : HKT> {
+    /// The final result of this operation.
+    type Output;
+
+    /// Feeds an item to this operation.
+    ///
+    /// Returns [`ControlFlow::Break`] when no further items are needed to
+    /// compute the [`Self::Output`][Consumer::Output]. Such a consumer may
+    /// nonetheless still be fed items (_e.g._, when other consumers in the
+    /// same tuple still need some), which it must then ignore.
+    fn feed (
+        &mut self,
+        item: &'_ A!(Item<'_>),
+    ) -> ControlFlow<()>
+    ;
+
+    /// Ends this operation, yielding its final result.
+    fn finish (
+        self,
+    ) -> Self::Output
+    ;
+}
+
+macro_rules! impl_for_tuples {(
+    $(
+        ( $($C:ident $i:tt),* )
+    )*
+) => (
+    $(
+        impl<Item : HKT, $($C : Consumer<Item>),*>
+            Consumer<Item>
+        for
+            ( $($C ,)* )
+        {
+            type Output = ( $($C::Output ,)* );
+
+            fn feed (
+                self: &'_ mut Self,
+                item: &'_ A!(Item<'_>),
+            ) -> ControlFlow<()>
+            {
+                let mut ret = ControlFlow::Break(());
+                $(
+                    if let ControlFlow::Continue(()) = self.$i.feed(item) {
+                        ret = ControlFlow::Continue(());
+                    }
+                )*
+                ret
+            }
+
+            fn finish (
+                self: Self,
+            ) -> Self::Output
+            {
+                ( $(self.$i.finish() ,)* )
+            }
+        }
+    )*
+)}
+
+impl_for_tuples! {
+    (C0 0)
+    (C0 0, C1 1)
+    (C0 0, C1 1, C2 2)
+    (C0 0, C1 1, C2 2, C3 3)
+    (C0 0, C1 1, C2 2, C3 3, C4 4)
+    (C0 0, C1 1, C2 2, C3 3, C4 4, C5 5)
+    (C0 0, C1 1, C2 2, C3 3, C4 4, C5 5, C6 6)
+    (C0 0, C1 1, C2 2, C3 3, C4 4, C5 5, C6 6, C7 7)
+}
+
+/// The [`Consumer`] constructors, for items of the [`HKT`][trait@HKT] `Item`,
+/// handed by [`.fold_many()`][LendingIterator::fold_many()] to its closure.
+///
+/// Since `Item` is known, the closures given to these constructors need
+/// neither turbofishing nor type annotations.
+pub
+struct Consumers<Item : HKT> {
+    pub(in crate)
+    _phantom_ty: ::core::marker::PhantomData<fn() -> Item>,
+}
+
+impl<Item : HKT> Clone for Consumers<Item> {
+    fn clone (self: &'_ Consumers<Item>)
+      -> Consumers<Item>
+    {
+        *self
+    }
+}
+
+impl<Item : HKT> Copy for Consumers<Item> {}
+
+impl<Item : HKT> Consumers<Item> {
+    /// See [`count()`].
+    pub
+    fn count (self: Consumers<Item>)
+      -> Count
+    {
+        count()
+    }
+
+    /// See [`fold()`].
+    pub
+    fn fold<Acc, F> (
+        self: Consumers<Item>,
+        acc: Acc,
+        f: F,
+    ) -> Fold<Acc, F>
+    where
+        F : FnMut(Acc, &'_ A!(Item<'_>)) -> Acc,
+    {
+        fold::<Item, Acc, F>(acc, f)
+    }
+
+    /// See [`all()`].
+    pub
+    fn all<F> (
+        self: Consumers<Item>,
+        predicate: F,
+    ) -> All<F>
+    where
+        F : FnMut(&'_ A!(Item<'_>)) -> bool,
+    {
+        all::<Item, F>(predicate)
+    }
+
+    /// See [`any()`].
+    pub
+    fn any<F> (
+        self: Consumers<Item>,
+        predicate: F,
+    ) -> Any<F>
+    where
+        F : FnMut(&'_ A!(Item<'_>)) -> bool,
+    {
+        any::<Item, F>(predicate)
+    }
+
+    /// See [`position()`].
+    pub
+    fn position<F> (
+        self: Consumers<Item>,
+        predicate: F,
+    ) -> Position<F>
+    where
+        F : FnMut(&'_ A!(Item<'_>)) -> bool,
+    {
+        position::<Item, F>(predicate)
+    }
+}
+
+/// [`Consumer`] counterpart of [`LendingIterator::count()`].
+pub
+fn count ()
+  -> Count
+{
+    Count { count: 0 }
+}
+
+/// The [`Consumer`] returned by [`count()`].
+pub
+struct Count {
+    pub(in crate)
+    count: usize,
+}
+
+impl<Item : HKT> Consumer<Item> for Count {
+    type Output = usize;
+
+    fn feed (self: &'_ mut Count, _: &'_ A!(Item<'_>))
+      -> ControlFlow<()>
+    {
+        self.count += 1;
+        ControlFlow::Continue(())
+    }
+
+    fn finish (self: Count)
+      -> usize
+    {
+        self.count
+    }
+}
+
+/// [`Consumer`] counterpart of [`LendingIterator::fold()`].
+pub
+fn fold<Item, Acc, F> (
+    acc: Acc,
+    f: F,
+) -> Fold<Acc, F>
+where
+    Item : HKT,
+    F : FnMut(Acc, &'_ A!(Item<'_>)) -> Acc,
+{
+    Fold { acc: Some(acc), f }
+}
+
+/// The [`Consumer`] returned by [`fold()`].
+pub
+struct Fold<Acc, F> {
+    // Only `None` while `f` is running.
+    pub(in crate)
+    acc: Option<Acc>,
+
+    pub(in crate)
+    f: F,
+}
+
+impl<Item, Acc, F> Consumer<Item> for Fold<Acc, F>
+where
+    Item : HKT,
+    F : FnMut(Acc, &'_ A!(Item<'_>)) -> Acc,
+{
+    type Output = Acc;
+
+    fn feed (self: &'_ mut Fold<Acc, F>, item: &'_ A!(Item<'_>))
+      -> ControlFlow<()>
+    {
+        let acc = self.acc.take().unwrap();
+        self.acc = Some((self.f)(acc, item));
+        ControlFlow::Continue(())
+    }
+
+    fn finish (self: Fold<Acc, F>)
+      -> Acc
+    {
+        self.acc.unwrap()
+    }
+}
+
+/// [`Consumer`] counterpart of [`LendingIterator::all()`].
+pub
+fn all<Item, F> (
+    predicate: F,
+) -> All<F>
+where
+    Item : HKT,
+    F : FnMut(&'_ A!(Item<'_>)) -> bool,
+{
+    All { predicate, all: true }
+}
+
+/// The [`Consumer`] returned by [`all()`].
+pub
+struct All<F> {
+    pub(in crate)
+    predicate: F,
+
+    pub(in crate)
+    all: bool,
+}
+
+impl<Item, F> Consumer<Item> for All<F>
+where
+    Item : HKT,
+    F : FnMut(&'_ A!(Item<'_>)) -> bool,
+{
+    type Output = bool;
+
+    fn feed (self: &'_ mut All<F>, item: &'_ A!(Item<'_>))
+      -> ControlFlow<()>
+    {
+        if self.all && (self.predicate)(item).not() {
+            self.all = false;
+        }
+        if self.all {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+
+    fn finish (self: All<F>)
+      -> bool
+    {
+        self.all
+    }
+}
+
+/// [`Consumer`] counterpart of [`LendingIterator::any()`].
+pub
+fn any<Item, F> (
+    predicate: F,
+) -> Any<F>
+where
+    Item : HKT,
+    F : FnMut(&'_ A!(Item<'_>)) -> bool,
+{
+    Any { predicate, any: false }
+}
+
+/// The [`Consumer`] returned by [`any()`].
+pub
+struct Any<F> {
+    pub(in crate)
+    predicate: F,
+
+    pub(in crate)
+    any: bool,
+}
+
+impl<Item, F> Consumer<Item> for Any<F>
+where
+    Item : HKT,
+    F : FnMut(&'_ A!(Item<'_>)) -> bool,
+{
+    type Output = bool;
+
+    fn feed (self: &'_ mut Any<F>, item: &'_ A!(Item<'_>))
+      -> ControlFlow<()>
+    {
+        if self.any.not() && (self.predicate)(item) {
+            self.any = true;
+        }
+        if self.any {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn finish (self: Any<F>)
+      -> bool
+    {
+        self.any
+    }
+}
+
+/// [`Consumer`] counterpart of [`LendingIterator::position()`].
+pub
+fn position<Item, F> (
+    predicate: F,
+) -> Position<F>
+where
+    Item : HKT,
+    F : FnMut(&'_ A!(Item<'_>)) -> bool,
+{
+    Position { predicate, count: 0, position: None }
+}
+
+/// The [`Consumer`] returned by [`position()`].
+pub
+struct Position<F> {
+    pub(in crate)
+    predicate: F,
+
+    pub(in crate)
+    count: usize,
+
+    pub(in crate)
+    position: Option<usize>,
+}
+
+impl<Item, F> Consumer<Item> for Position<F>
+where
+    Item : HKT,
+    F : FnMut(&'_ A!(Item<'_>)) -> bool,
+{
+    type Output = Option<usize>;
+
+    fn feed (self: &'_ mut Position<F>, item: &'_ A!(Item<'_>))
+      -> ControlFlow<()>
+    {
+        if self.position.is_none() {
+            if (self.predicate)(item) {
+                self.position = Some(self.count);
+            }
+            self.count += 1;
+        }
+        if self.position.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn finish (self: Position<F>)
+      -> Option<usize>
+    {
+        self.position
+    }
+}
+
+/// The reduction logic of the [`Consumer`]s fed owned projections (_keys_)
+/// of the items, shared with their [`LendingIterator`] method counterparts
+/// (_e.g._, [`LendingIterator::min_by_key()`]).
+pub(in crate)
+trait Accumulator {
+    type Key;
+
+    type Output;
+
+    fn push (
+        &mut self,
+        key: Self::Key,
+    )
+    ;
+
+    fn finish (
+        self,
+    ) -> Self::Output
+    ;
+}
+
+/// Reduces the keys, obtained by calling `f` on each item of `iter`, through
+/// `acc`.
+pub(in crate)
+fn accumulate<I, A> (
+    iter: I,
+    acc: A,
+    mut f: impl FnMut(Item<'_, I>) -> A::Key,
+) -> A::Output
+where
+    I : LendingIterator,
+    A : Accumulator,
+{
+    let mut iter = iter;
+    iter.try_fold(acc, |mut acc, item| {
+            acc.push(f(item));
+            Ok(acc)
+        })
+        .unwrap_or_else(|unreachable: ǃ| unreachable)
+        .finish()
+}
+
+pub(in crate)
+struct Summed<S> {
+    // Only `None` while adding.
+    sum: Option<S>,
+}
+
+impl<S> Summed<S>
+where
+    S : ::core::iter::Sum,
+{
+    pub(in crate)
+    fn new ()
+      -> Summed<S>
+    {
+        Self { sum: Some(::core::iter::empty::<S>().sum()) }
+    }
+}
+
+impl<S> Accumulator for Summed<S>
+where
+    S : ::core::iter::Sum,
+{
+    type Key = S;
+
+    type Output = S;
+
+    fn push (self: &'_ mut Summed<S>, key: S)
+    {
+        self.sum = Some([self.sum.take().unwrap(), key].into_iter().sum());
+    }
+
+    fn finish (self: Summed<S>)
+      -> S
+    {
+        self.sum.unwrap()
+    }
+}
+
+pub(in crate)
+struct Multiplied<P> {
+    // Only `None` while multiplying.
+    product: Option<P>,
+}
+
+impl<P> Multiplied<P>
+where
+    P : ::core::iter::Product,
+{
+    pub(in crate)
+    fn new ()
+      -> Multiplied<P>
+    {
+        Self { product: Some(::core::iter::empty::<P>().product()) }
+    }
+}
+
+impl<P> Accumulator for Multiplied<P>
+where
+    P : ::core::iter::Product,
+{
+    type Key = P;
+
+    type Output = P;
+
+    fn push (self: &'_ mut Multiplied<P>, key: P)
+    {
+        self.product = Some([self.product.take().unwrap(), key].into_iter().product());
+    }
+
+    fn finish (self: Multiplied<P>)
+      -> P
+    {
+        self.product.unwrap()
+    }
+}
+
+pub(in crate)
+struct MinKey<K> {
+    count: usize,
+    min: Option<(usize, K)>,
+}
+
+impl<K : Ord> MinKey<K> {
+    pub(in crate)
+    fn new ()
+      -> MinKey<K>
+    {
+        Self { count: 0, min: None }
+    }
+}
+
+impl<K : Ord> Accumulator for MinKey<K> {
+    type Key = K;
+
+    type Output = Option<(usize, K)>;
+
+    fn push (self: &'_ mut MinKey<K>, key: K)
+    {
+        // On ties, the first one is kept.
+        if matches!(self.min, Some((_, ref min)) if *min <= key).not() {
+            self.min = Some((self.count, key));
+        }
+        self.count += 1;
+    }
+
+    fn finish (self: MinKey<K>)
+      -> Option<(usize, K)>
+    {
+        self.min
+    }
+}
+
+pub(in crate)
+struct MaxKey<K> {
+    count: usize,
+    max: Option<(usize, K)>,
+}
+
+impl<K : Ord> MaxKey<K> {
+    pub(in crate)
+    fn new ()
+      -> MaxKey<K>
+    {
+        Self { count: 0, max: None }
+    }
+}
+
+impl<K : Ord> Accumulator for MaxKey<K> {
+    type Key = K;
+
+    type Output = Option<(usize, K)>;
+
+    fn push (self: &'_ mut MaxKey<K>, key: K)
+    {
+        // On ties, the last one is kept.
+        if matches!(self.max, Some((_, ref max)) if *max > key).not() {
+            self.max = Some((self.count, key));
+        }
+        self.count += 1;
+    }
+
+    fn finish (self: MaxKey<K>)
+      -> Option<(usize, K)>
+    {
+        self.max
+    }
+}
+
+pub(in crate)
+struct MinMaxKey<K> {
+    count: usize,
+    minmax: Option<((usize, K), (usize, K))>,
+}
+
+impl<K : Ord + Clone> MinMaxKey<K> {
+    pub(in crate)
+    fn new ()
+      -> MinMaxKey<K>
+    {
+        Self { count: 0, minmax: None }
+    }
+}
+
+impl<K : Ord + Clone> Accumulator for MinMaxKey<K> {
+    type Key = K;
+
+    type Output = Option<((usize, K), (usize, K))>;
+
+    fn push (self: &'_ mut MinMaxKey<K>, key: K)
+    {
+        let i = self.count;
+        match self.minmax {
+            | None => {
+                self.minmax = Some(((i, key.clone()), (i, key)));
+            },
+            | Some((ref mut min, ref mut max)) => {
+                if key < min.1 {
+                    *min = (i, key);
+                } else if key >= max.1 {
+                    *max = (i, key);
+                }
+            },
+        }
+        self.count += 1;
+    }
+
+    fn finish (self: MinMaxKey<K>)
+      -> Option<((usize, K), (usize, K))>
+    {
+        self.minmax
+    }
+}
+
+#[apply(cfg_alloc)]
+pub(in crate)
+struct Counts<K> {
+    counts: ::alloc::collections::BTreeMap<K, usize>,
+}
+
+#[apply(cfg_alloc)]
+impl<K : Ord> Counts<K> {
+    pub(in crate)
+    fn new ()
+      -> Counts<K>
+    {
+        Self { counts: <_>::default() }
+    }
+}
+
+#[apply(cfg_alloc)]
+impl<K : Ord> Accumulator for Counts<K> {
+    type Key = K;
+
+    type Output = ::alloc::collections::BTreeMap<K, usize>;
+
+    fn push (self: &'_ mut Counts<K>, key: K)
+    {
+        *self.counts.entry(key).or_insert(0) += 1;
+    }
+
+    fn finish (self: Counts<K>)
+      -> ::alloc::collections::BTreeMap<K, usize>
+    {
+        self.counts
+    }
+}
+
+macro_rules! key_consumers {(
+    $(
+        $(#[apply($cfg:ident)])?
+        fn $fname:ident -> $Name:ident<$K:ident, F>
+        where {
+            $($bounds:tt)*
+        }
+        => $Acc:ident = $Output:ty;
+    )*
+) => (
+    $(
+        #[doc = concat!(
+            "[`Consumer`] counterpart of [`LendingIterator::",
+            stringify!($fname), "()`].",
+        )]
+        $(#[apply($cfg)])?
+        pub
+        fn $fname<Item, $K, F> (
+            f: F,
+        ) -> $Name<$K, F>
+        where
+            Item : HKT,
+            $($bounds)*
+            F : FnMut(&'_ A!(Item<'_>)) -> $K,
+        {
+            $Name { f, acc: $Acc::new() }
+        }
+
+        #[doc = concat!(
+            "The [`Consumer`] returned by [`", stringify!($fname), "()`].",
+        )]
+        $(#[apply($cfg)])?
+        pub
+        struct $Name<$K, F> {
+            pub(in crate)
+            f: F,
+
+            pub(in crate)
+            acc: $Acc<$K>,
+        }
+
+        $(#[apply($cfg)])?
+        impl<Item, $K, F> Consumer<Item> for $Name<$K, F>
+        where
+            Item : HKT,
+            $($bounds)*
+            F : FnMut(&'_ A!(Item<'_>)) -> $K,
+        {
+            type Output = $Output;
+
+            fn feed (self: &'_ mut $Name<$K, F>, item: &'_ A!(Item<'_>))
+              -> ControlFlow<()>
+            {
+                self.acc.push((self.f)(item));
+                ControlFlow::Continue(())
+            }
+
+            fn finish (self: $Name<$K, F>)
+              -> $Output
+            {
+                self.acc.finish()
+            }
+        }
+    )*
+
+    impl<Item : HKT> Consumers<Item> {
+        $(
+            #[doc = concat!("See [`", stringify!($fname), "()`].")]
+            $(#[apply($cfg)])?
+            pub
+            fn $fname<$K, F> (
+                self: Consumers<Item>,
+                f: F,
+            ) -> $Name<$K, F>
+            where
+                $($bounds)*
+                F : FnMut(&'_ A!(Item<'_>)) -> $K,
+            {
+                $fname::<Item, $K, F>(f)
+            }
+        )*
+    }
+)}
+
+key_consumers! {
+    fn sum_by -> SumBy<S, F>
+    where {
+        S : ::core::iter::Sum,
+    }
+    => Summed = S;
+
+    fn product_by -> ProductBy<P, F>
+    where {
+        P : ::core::iter::Product,
+    }
+    => Multiplied = P;
+
+    fn min_by_key -> MinByKey<K, F>
+    where {
+        K : Ord,
+    }
+    => MinKey = Option<(usize, K)>;
+
+    fn max_by_key -> MaxByKey<K, F>
+    where {
+        K : Ord,
+    }
+    => MaxKey = Option<(usize, K)>;
+
+    fn minmax_by_key -> MinMaxByKey<K, F>
+    where {
+        K : Ord + Clone,
+    }
+    => MinMaxKey = Option<((usize, K), (usize, K))>;
+
+    #[apply(cfg_alloc)]
+    fn counts_by -> CountsBy<K, F>
+    where {
+        K : Ord,
+    }
+    => Counts = ::alloc::collections::BTreeMap<K, usize>;
+}
@@ -88,6 +88,16 @@ trait LendingIteratorDyn {
         self: &'_ mut Self,
     ) -> Option<A!(Self::Item<'_>)>
     ;
+
+    /// A `dyn`-safe version of [`LendingIterator::size_hint()`].
+    ///
+    /// Same remarks as for [`Self::dyn_next()`] apply.
+    fn dyn_size_hint (
+        &self,
+    ) -> (usize, Option<usize>)
+    {
+        (0, None)
+    }
 }
 
 /// `impl LendingIterator : LendingIteratorDyn`
@@ -104,6 +114,13 @@ for
     {
         self.next()
     }
+
+    fn dyn_size_hint (
+        self: &'_ T,
+    ) -> (usize, Option<usize>)
+    {
+        self.size_hint()
+    }
 }
 
 with_auto_traits! {( $($AutoTraits:tt)* ) => (
@@ -135,6 +152,17 @@ with_auto_traits! {( $($AutoTraits:tt)* ) => (
         {
             self.dyn_next()
         }
+
+        fn size_hint (
+            self: &'_ (dyn
+                'usability +
+                LendingIteratorDyn<Item = Item> +
+                $($AutoTraits)*
+            ),
+        ) -> (usize, Option<usize>)
+        {
+            self.dyn_size_hint()
+        }
     }
 )}
 
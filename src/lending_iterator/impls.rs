@@ -15,12 +15,40 @@ for
         Item<'next, I>
     ;
 
+    const ඞIS_FUSED: bool = I::ඞIS_FUSED;
+
     fn next<'next> (
         self: &'next mut &'r mut I,
     ) -> Option<Item<'next, Self>>
     {
         (*self).next()
     }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut &'r mut I,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (*self).advance_by(count)
+    }
+
+    #[inline]
+    fn nth<'nth> (
+        self: &'nth mut &'r mut I,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (*self).nth(n)
+    }
+
+    #[inline]
+    fn size_hint (
+        self: &'_ &'r mut I,
+    ) -> (usize, Option<usize>)
+    {
+        (**self).size_hint()
+    }
 }
 
 #[gat]
@@ -38,11 +66,39 @@ where
         Item<'next, I>
     ;
 
+    const ඞIS_FUSED: bool = I::ඞIS_FUSED;
+
     fn next<'next> (
         self: &'next mut ::core::pin::Pin<&'r mut I>,
     ) -> Option<Item<'next, Self>>
     {
-        (&mut **self).next()
+        (**self).next()
+    }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut ::core::pin::Pin<&'r mut I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (**self).advance_by(count)
+    }
+
+    #[inline]
+    fn nth<'nth> (
+        self: &'nth mut ::core::pin::Pin<&'r mut I>,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (**self).nth(n)
+    }
+
+    #[inline]
+    fn size_hint (
+        self: &'_ ::core::pin::Pin<&'r mut I>,
+    ) -> (usize, Option<usize>)
+    {
+        (**self).size_hint()
     }
 }
 
@@ -60,11 +116,39 @@ for
         Item<'next, I>
     ;
 
+    const ඞIS_FUSED: bool = I::ඞIS_FUSED;
+
     fn next<'next> (
         self: &'next mut ::alloc::boxed::Box<I>,
     ) -> Option<Item<'next, Self>>
     {
-        (&mut **self).next()
+        (**self).next()
+    }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut ::alloc::boxed::Box<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (**self).advance_by(count)
+    }
+
+    #[inline]
+    fn nth<'nth> (
+        self: &'nth mut ::alloc::boxed::Box<I>,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (**self).nth(n)
+    }
+
+    #[inline]
+    fn size_hint (
+        self: &'_ ::alloc::boxed::Box<I>,
+    ) -> (usize, Option<usize>)
+    {
+        (**self).size_hint()
     }
 }
 
@@ -84,10 +168,211 @@ where
         Item<'next, I>
     ;
 
+    const ඞIS_FUSED: bool = I::ඞIS_FUSED;
+
     fn next<'next> (
         self: &'next mut ::core::pin::Pin<::alloc::boxed::Box<I>>,
     ) -> Option<Item<'next, Self>>
     {
-        (&mut **self).next()
+        (**self).next()
+    }
+
+    #[inline]
+    fn advance_by (
+        self: &'_ mut ::core::pin::Pin<::alloc::boxed::Box<I>>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (**self).advance_by(count)
+    }
+
+    #[inline]
+    fn nth<'nth> (
+        self: &'nth mut ::core::pin::Pin<::alloc::boxed::Box<I>>,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (**self).nth(n)
+    }
+
+    #[inline]
+    fn size_hint (
+        self: &'_ ::core::pin::Pin<::alloc::boxed::Box<I>>,
+    ) -> (usize, Option<usize>)
+    {
+        (**self).size_hint()
+    }
+}
+
+impl<I : ?Sized + ExactSizeLendingIterator>
+    ExactSizeLendingIterator
+for
+    &'_ mut I
+{}
+
+#[apply(cfg_alloc)]
+impl<I : ?Sized + ExactSizeLendingIterator>
+    ExactSizeLendingIterator
+for
+    ::alloc::boxed::Box<I>
+{}
+
+impl<I : ?Sized + FusedLendingIterator>
+    FusedLendingIterator
+for
+    &'_ mut I
+{}
+
+#[apply(cfg_alloc)]
+impl<I : ?Sized + FusedLendingIterator>
+    FusedLendingIterator
+for
+    ::alloc::boxed::Box<I>
+{}
+
+impl<I : ?Sized + FusedLendingIterator>
+    FusedLendingIterator
+for
+    ::core::pin::Pin<&'_ mut I>
+where
+    I : ::core::marker::Unpin,
+{}
+
+#[apply(cfg_alloc)]
+impl<I : ?Sized + FusedLendingIterator>
+    FusedLendingIterator
+for
+    ::core::pin::Pin<::alloc::boxed::Box<I>>
+where
+    I : ::core::marker::Unpin,
+{}
+
+impl<'r, I : ?Sized + DoubleEndedLendingIterator>
+    DoubleEndedLendingIterator
+for
+    &'r mut I
+{
+    fn next_back<'next> (
+        self: &'next mut &'r mut I,
+    ) -> Option<Item<'next, Self>>
+    {
+        (*self).next_back()
+    }
+
+    #[inline]
+    fn advance_back_by (
+        self: &'_ mut &'r mut I,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (*self).advance_back_by(count)
+    }
+
+    #[inline]
+    fn nth_back<'nth> (
+        self: &'nth mut &'r mut I,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (*self).nth_back(n)
+    }
+}
+
+impl<'r, I : ?Sized + DoubleEndedLendingIterator>
+    DoubleEndedLendingIterator
+for
+    ::core::pin::Pin<&'r mut I>
+where
+    I : ::core::marker::Unpin,
+{
+    fn next_back<'next> (
+        self: &'next mut ::core::pin::Pin<&'r mut I>,
+    ) -> Option<Item<'next, Self>>
+    {
+        (**self).next_back()
+    }
+
+    #[inline]
+    fn advance_back_by (
+        self: &'_ mut ::core::pin::Pin<&'r mut I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (**self).advance_back_by(count)
+    }
+
+    #[inline]
+    fn nth_back<'nth> (
+        self: &'nth mut ::core::pin::Pin<&'r mut I>,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (**self).nth_back(n)
+    }
+}
+
+#[apply(cfg_alloc)]
+impl<I : ?Sized + DoubleEndedLendingIterator>
+    DoubleEndedLendingIterator
+for
+    ::alloc::boxed::Box<I>
+{
+    fn next_back<'next> (
+        self: &'next mut ::alloc::boxed::Box<I>,
+    ) -> Option<Item<'next, Self>>
+    {
+        (**self).next_back()
+    }
+
+    #[inline]
+    fn advance_back_by (
+        self: &'_ mut ::alloc::boxed::Box<I>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (**self).advance_back_by(count)
+    }
+
+    #[inline]
+    fn nth_back<'nth> (
+        self: &'nth mut ::alloc::boxed::Box<I>,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (**self).nth_back(n)
+    }
+}
+
+#[apply(cfg_alloc)]
+impl<I : ?Sized + DoubleEndedLendingIterator>
+    DoubleEndedLendingIterator
+for
+    ::core::pin::Pin<::alloc::boxed::Box<I>>
+where
+    I : ::core::marker::Unpin,
+{
+    fn next_back<'next> (
+        self: &'next mut ::core::pin::Pin<::alloc::boxed::Box<I>>,
+    ) -> Option<Item<'next, Self>>
+    {
+        (**self).next_back()
+    }
+
+    #[inline]
+    fn advance_back_by (
+        self: &'_ mut ::core::pin::Pin<::alloc::boxed::Box<I>>,
+        count: usize,
+    ) -> Result<(), NonZeroUsize>
+    {
+        (**self).advance_back_by(count)
+    }
+
+    #[inline]
+    fn nth_back<'nth> (
+        self: &'nth mut ::core::pin::Pin<::alloc::boxed::Box<I>>,
+        n: usize,
+    ) -> Option<Item<'nth, Self>>
+    {
+        (**self).nth_back(n)
     }
 }
@@ -11,7 +11,7 @@ fn inlined_windows_mut ()
     let mut start = 0;
     let mut window_iter =
         lending_iterator::from_fn::<HKT!(&mut [u8]), _, _>(slice, |it| Some(it))
-            .filter_map::<HKT!(&mut [u8]), _>(|[], slice| Some({
+            .map_while::<HKT!(&mut [u8]), _>(|[], slice| Some({
                 let to_yield = slice.get_mut(start ..)?.get_mut(..2)?;
                 start += 1;
                 to_yield
@@ -30,3 +30,804 @@ fn inlined_windows_mut ()
         array,
     );
 }
+
+#[test]
+fn nth_and_advance_by ()
+{
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+    // Through the default implementations:
+    let mut iter =
+        lending_iterator::repeat_mut(0)
+            .take(5)
+            .filter(|_| true)
+    ;
+    assert_eq!(iter.nth(1).copied(), Some(0));
+    assert_eq!(iter.advance_by(5), Err(NonZeroUsize::new(2).unwrap()));
+    assert!(iter.next().is_none());
+    // Through the overridden ones:
+    let mut windows = lending_iterator::windows_mut::<_, 3>(&mut array);
+    assert_eq!(windows.nth(2), Some(&mut [2, 3, 4]));
+    assert_eq!(windows.advance_by(1), Ok(()));
+    assert_eq!(windows.advance_by(2), Err(NonZeroUsize::new(1).unwrap()));
+    assert!(windows.next().is_none());
+    let mut skipped = lending_iterator::windows_mut::<_, 2>(&mut array).skip(2).take(3);
+    assert_eq!(skipped.nth(1), Some(&mut [3, 4]));
+    assert_eq!(skipped.advance_by(2), Err(NonZeroUsize::new(1).unwrap()));
+    assert_eq!(lending_iterator::from_iter(0 .. 10).nth(4), Some(4));
+}
+
+#[test]
+fn size_hints ()
+{
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+    let mut windows = lending_iterator::windows_mut::<_, 3>(&mut array);
+    assert_eq!(windows.len(), 5);
+    windows.next();
+    assert_eq!(windows.size_hint(), (4, Some(4)));
+    assert_eq!(windows.by_ref().skip(1).take(2).size_hint(), (2, Some(2)));
+    assert_eq!(windows.by_ref().take(10).len(), 4);
+    assert_eq!(windows.by_ref().filter(|_| true).size_hint(), (0, Some(4)));
+    let squares =
+        windows
+            .map_into_iter(|&mut [a, ..]| a * a)
+    ;
+    assert_eq!(squares.len(), 4);
+    assert_eq!(lending_iterator::windows_mut::<_, 8>(&mut array).count(), 0);
+    assert_eq!(lending_iterator::windows_mut::<_, 0>(&mut array).count(), 8);
+    assert_eq!(
+        lending_iterator::repeat_mut(()).take(4).size_hint(),
+        (4, Some(4)),
+    );
+    assert_eq!(lending_iterator::from_iter(0 .. 4).fuse().len(), 4);
+}
+
+#[test]
+fn double_ended ()
+{
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+    let mut windows = lending_iterator::windows_mut::<_, 3>(&mut array);
+    assert_eq!(windows.next_back(), Some(&mut [4, 5, 6]));
+    assert_eq!(windows.nth_back(1), Some(&mut [2, 3, 4]));
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows.next(), Some(&mut [0, 1, 2]));
+    assert_eq!(windows.next_back(), Some(&mut [1, 2, 3]));
+    assert!(windows.next().is_none());
+    assert!(windows.next_back().is_none());
+
+    let mut windows = lending_iterator::windows_mut::<_, 0>(&mut array);
+    assert_eq!(windows.advance_back_by(8), Ok(()));
+    assert!(windows.next().is_none());
+
+    let mut evens =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .filter(|&&mut [a, _]| a % 2 == 0)
+            .map_into_iter(|&mut [a, b]| a + b)
+    ;
+    assert_eq!(evens.next_back(), Some(4 + 5));
+    assert_eq!(evens.next(), Some(1));
+    assert_eq!(
+        lending_iterator::from_iter(0 .. 5)
+            .rev()
+            .rfold(0, |acc, x| 10 * acc + x),
+        1234,
+    );
+    assert_eq!(
+        lending_iterator::from_iter(0 .. 5)
+            .fuse()
+            .rfind(|&x| x % 3 == 0),
+        Some(3),
+    );
+}
+
+#[test]
+fn filter_map ()
+{
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+    let mut seconds_after_odd =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .filter_map::<HKT!(&mut u8), _>(|[], [a, b]| {
+                if *a % 2 == 1 { Some(b) } else { None }
+            })
+    ;
+    // Both ends skip the items mapped to `None`.
+    assert_eq!(seconds_after_odd.next(), Some(&mut 2));
+    assert_eq!(seconds_after_odd.next_back(), Some(&mut 6));
+    assert_eq!(seconds_after_odd.next_back(), Some(&mut 4));
+    assert!(seconds_after_odd.next().is_none());
+
+    let mut sums_after_odd =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .filter_map_into_iter(|&mut [a, b]| {
+                if a % 2 == 1 { Some(a + b) } else { None }
+            })
+    ;
+    assert_eq!(sums_after_odd.next(), Some(1 + 2));
+    assert_eq!(sums_after_odd.next_back(), Some(5 + 6));
+    assert_eq!(sums_after_odd.next(), Some(3 + 4));
+    assert!(sums_after_odd.next_back().is_none());
+}
+
+#[test]
+fn fuse ()
+{
+    fn assert_fused<I : ::core::iter::FusedIterator> (_: &I)
+    {}
+    fn is_fused<I : LendingIterator> (_: &I)
+      -> bool
+    {
+        I::ඞIS_FUSED
+    }
+
+    let mut n = 0;
+    let mut flaky =
+        lending_iterator::from_fn::<HKT!(&mut u8), _, _>(0, move |state| {
+            n += 1;
+            if n % 2 == 0 { None } else { Some(state) }
+        })
+        .fuse()
+    ;
+    // Not statically known to be fused: `Fuse` does the bookkeeping.
+    assert!(!is_fused(flaky.0.as_ref().unwrap()));
+    assert!(flaky.next().is_some());
+    assert!(flaky.next().is_none());
+    assert!(flaky.next().is_none());
+
+    let mut array = [0, 1, 2];
+    let mut windows = lending_iterator::windows_mut::<_, 2>(&mut array).fuse();
+    // Statically known to be fused: `Fuse` is a mere pass-through.
+    assert!(is_fused(windows.0.as_ref().unwrap()));
+    assert!(windows.nth(1).is_some());
+    assert!(windows.next().is_none());
+    assert!(windows.next().is_none());
+    let iter = windows.map_into_iter(|&mut [a, _]| a);
+    assert_fused(&iter);
+}
+
+#[test]
+fn take_and_skip_while ()
+{
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+    let mut windows =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .skip_while(|&&mut [a, _]| a < 2)
+            .take_while(|&&mut [_, b]| b < 6)
+    ;
+    assert_eq!(windows.size_hint(), (0, Some(6)));
+    while let Some(&mut [_, ref mut b]) = windows.next() {
+        *b = 0;
+    }
+    assert!(windows.next().is_none());
+    assert_eq!(array, [0, 1, 2, 0, 0, 0, 6]);
+    let mut until = lending_iterator::from_iter(1 ..).take_until(|&x| x % 3 == 0);
+    assert_eq!(until.next(), Some(1));
+    assert_eq!(until.next(), Some(2));
+    assert_eq!(until.next(), Some(3));
+    assert_eq!(until.next(), None);
+}
+
+#[test]
+fn map_while ()
+{
+    fn assert_fused<I : FusedLendingIterator> (_: &I)
+    {}
+
+    let mut array = [1, 2, -3, 4];
+    let mut firsts =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .map_while::<HKT!(&mut i32), _>(|[], [a, b]| {
+                if *b > 0 { Some(a) } else { None }
+            })
+    ;
+    assert_fused(&firsts);
+    assert_eq!(firsts.size_hint(), (0, Some(3)));
+    *firsts.next().unwrap() *= 10;
+    assert!(firsts.next().is_none());
+    assert_eq!(firsts.size_hint(), (0, Some(0)));
+    // The source, which still has a `[-3, 4]` window, is no longer queried.
+    assert!(firsts.next().is_none());
+    assert_eq!(array, [10, 2, -3, 4]);
+}
+
+#[test]
+fn scan ()
+{
+    let mut array = [1, 2, 3, 4];
+    let mut iter =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .scan::<HKT!(&mut i32), _, _>(0, |[], total, [a, b]| {
+                *total += *a;
+                if *total > 3 { None } else { Some(b) }
+            })
+    ;
+    *iter.next().unwrap() *= 10;
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+    assert_eq!(iter.into_state(), 21);
+    assert_eq!(array, [1, 20, 3, 4]);
+
+    // Lending from the state instead.
+    let mut iter =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .scan::<HKT!(&mut i32), _, _>(0, |[], total, &mut [a, _]| {
+                *total += a;
+                Some(total)
+            })
+    ;
+    assert_eq!(iter.next(), Some(&mut 1));
+    assert_eq!(iter.next(), Some(&mut 21));
+
+    // Fused even over a non-fused source.
+    fn assert_fused<I : FusedLendingIterator> (_: &I)
+    {}
+    let mut n = 0;
+    let mut iter =
+        lending_iterator::from_fn::<HKT!(u8), _, _>((), |()| {
+            n += 1;
+            if n == 2 { None } else { Some(n) }
+        })
+        .scan::<HKT!(u8), _, _>((), |[], (), n| Some(n))
+    ;
+    assert_fused(&iter);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn chain ()
+{
+    let mut counter = 10;
+    let lending_tail = lending_iterator::from_fn::<HKT!(u8), _, _>((), |()| {
+        counter += 10;
+        if counter <= 30 { Some(counter) } else { None }
+    });
+    let mut iter = lending_iterator::from_iter(1 ..= 3_u8).chain(lending_tail);
+    assert_eq!(iter.size_hint(), (3, None));
+    assert_eq!(iter.nth(2), Some(3));
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(iter.next(), Some(30));
+    assert_eq!(iter.next(), None);
+
+    let mut iter =
+        lending_iterator::from_iter(1 ..= 3)
+            .chain(lending_iterator::from_iter([4, 5].iter().copied()))
+    ;
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.nth_back(1), Some(3));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn zip ()
+{
+    use adapters::EitherOrBoth::*;
+
+    let mut iter =
+        lending_iterator::from_iter(0 .. 5)
+            .zip(lending_iterator::from_iter(10 .. 13))
+    ;
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next_back(), Some((2, 12)));
+    assert_eq!(iter.next(), Some((0, 10)));
+    assert_eq!(iter.len(), 1);
+
+    let mut iter =
+        lending_iterator::from_iter(0 .. 3)
+            .zip_longest(lending_iterator::from_iter(10 .. 11))
+    ;
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next_back(), Some(Left(2)));
+    assert_eq!(iter.next(), Some(Both(0, 10)));
+    assert_eq!(iter.next(), Some(Left(1)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn enumerate_and_zip_iter ()
+{
+    let mut array = [0, 1, 2, 3, 4];
+    let mut iter = lending_iterator::windows_mut::<_, 2>(&mut array).enumerate();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert!(matches!(iter.nth(1), Some((1, &mut [1, 2]))));
+    assert!(matches!(iter.next_back(), Some((3, &mut [3, 4]))));
+    assert!(matches!(iter.next(), Some((2, &mut [2, 3]))));
+    assert!(iter.next().is_none());
+
+    let mut state = 0;
+    let mut iter =
+        lending_iterator::repeat_mut(&mut state)
+            .zip_iter(1 ..= 3)
+    ;
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    while let Some((value, state)) = iter.next() {
+        **state += value;
+    }
+    assert_eq!(state, 6);
+
+    let mut iter =
+        lending_iterator::from_fn::<HKT!(u8), _, _>(0, |n| { *n += 1; Some(*n) })
+            .zip_iter("abcde".chars())
+            .enumerate()
+    ;
+    assert_eq!(iter.nth(2), Some((2, ('c', 3))));
+    assert_eq!(iter.next(), Some((3, ('d', 4))));
+
+    let mut iter =
+        lending_iterator::from_fn::<HKT!(u8), _, _>(0, |n| { *n += 1; Some(*n) })
+            .zip_iter("ab".chars())
+    ;
+    assert_eq!(iter.nth(3), None);
+    // The lending side got advanced as well.
+    assert_eq!(iter.iter.next(), Some(5));
+
+    // Fused as soon as both sides are: no `.fuse()` needed.
+    fn assert_fused<I : FusedLendingIterator> (_: &I)
+    {}
+    let iter = lending_iterator::from_iter(0 .. 3).zip_iter(0 .. 3);
+    assert_fused(&iter);
+}
+
+#[test]
+fn peekable ()
+{
+    let mut n = 0;
+    let mut iter =
+        lending_iterator::from_fn::<HKT!(u8), _, _>((), |()| {
+            n += 1;
+            if n <= 4 { Some(n) } else { None }
+        })
+        .peekable()
+    ;
+    assert_eq!(iter.peek(), Some(&1));
+    *iter.peek_mut().unwrap() = 10;
+    assert_eq!(iter.next(), Some(10));
+    assert_eq!(iter.next_if(|&x| x > 2), None);
+    assert_eq!(iter.next_if_eq(&2), Some(2));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = lending_iterator::from_iter(0 .. 3).peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn flat_map ()
+{
+    let mut iter =
+        lending_iterator::from_iter(1 ..= 3)
+            .flat_map(|n| lending_iterator::from_iter(0 .. n))
+    ;
+    assert_eq!(iter.size_hint(), (0, None));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn chunks ()
+{
+    let mut chunks = lending_iterator::from_iter(0 .. 7).chunks(3);
+    assert_eq!(chunks.size_hint(), (3, Some(3)));
+    let mut chunk = chunks.next().unwrap();
+    assert_eq!(chunk.size_hint(), (3, Some(3)));
+    assert_eq!(chunk.next(), Some(0));
+    let mut chunk = chunks.next().unwrap();
+    assert_eq!(chunk.next(), Some(3));
+    assert_eq!(chunk.next(), Some(4));
+    assert_eq!(chunk.next(), Some(5));
+    assert_eq!(chunk.next(), None);
+    assert_eq!(chunks.size_hint(), (1, Some(1)));
+    let mut chunk = chunks.next().unwrap();
+    assert_eq!(chunk.next(), Some(6));
+    assert_eq!(chunk.next(), None);
+    assert!(chunks.next().is_none());
+
+    // No empty last chunk when the length is a multiple of the chunk size.
+    let mut chunks = lending_iterator::from_iter(0 .. 4).chunks(2);
+    assert_eq!(chunks.len(), 2);
+    let mut sums = [0; 3];
+    let mut i = 0;
+    while let Some(mut chunk) = chunks.next() {
+        while let Some(n) = chunk.next() {
+            sums[i] += n;
+        }
+        i += 1;
+    }
+    assert_eq!((i, sums), (2, [1, 5, 0]));
+}
+
+#[test]
+fn dedup_and_unique_by_key ()
+{
+    let mut iter =
+        lending_iterator::from_iter([1, 1, 2, 1, 1].iter().copied())
+            .dedup_by_key(|&x| x)
+    ;
+    assert_eq!(iter.size_hint(), (1, Some(5)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+
+    #[cfg(feature = "alloc")] {
+        let mut iter =
+            lending_iterator::from_iter([1, 2, 1, 3, 2].iter().copied())
+                .unique_by_key(|&x| x)
+        ;
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[test]
+fn step_by_and_inspect ()
+{
+    let mut iter = lending_iterator::from_iter(0 .. 10).step_by(3);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(6));
+    assert_eq!(iter.next(), None);
+
+    let mut seen = 0;
+    let mut iter =
+        lending_iterator::from_iter(1 ..= 4)
+            .inspect(|&x| seen += x)
+            .inspect_mut(|x| *x *= 2)
+    ;
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next_back(), Some(8));
+    assert_eq!(seen, 5);
+}
+
+#[test]
+fn cycle ()
+{
+    let mut array = [0, 1, 2];
+    let mut iter = lending_iterator::windows_mut::<_, 2>(&mut array).cycle();
+    assert_eq!(iter.size_hint(), (2, None));
+    assert!(matches!(iter.nth(2), Some(&mut [0, 1])));
+    assert!(matches!(iter.next(), Some(&mut [1, 2])));
+
+    let mut passes = 0;
+    let mut iter =
+        lending_iterator::from_iter(0 .. 0)
+            .cycle_with(|_| passes += 1)
+    ;
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(passes, 2);
+}
+
+#[test]
+fn intersperse_with ()
+{
+    use adapters::EitherOrBoth::*;
+    let mut array = [0, 1, 2, 3];
+    let mut separators = 0;
+    let mut iter =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .intersperse_with(|| { separators += 1; separators })
+    ;
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next(), Some(Left(&mut [0, 1])));
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next(), Some(Right(1)));
+    if let Some(Left([_, b])) = iter.next() {
+        *b = 42;
+    }
+    assert_eq!(iter.next(), Some(Right(2)));
+    assert_eq!(iter.next(), Some(Left(&mut [42, 3])));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(separators, 2);
+
+    // Without a `size_hint()` lower bound, separators get yielded along with
+    // the next item.
+    let mut iter =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .filter(|[a, _]| *a != 1)
+            .intersperse_with(|| ",")
+    ;
+    assert_eq!(iter.size_hint(), (0, Some(5)));
+    assert_eq!(iter.next(), Some(Left(&mut [0, 1])));
+    assert_eq!(iter.next(), Some(Both(&mut [42, 3], ",")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn interleave ()
+{
+    let mut iter = lending_iterator::interleave_shortest([
+        lending_iterator::from_iter(0 .. 5),
+        lending_iterator::from_iter(10 .. 12),
+    ]);
+    assert_eq!(iter.size_hint(), (0, Some(7)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(10));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(11));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = lending_iterator::interleave([
+        lending_iterator::from_iter(0 .. 2),
+        lending_iterator::from_iter(10 .. 10),
+        lending_iterator::from_iter(20 .. 23),
+    ]);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(21));
+    assert_eq!(iter.next(), Some(22));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn merge_by ()
+{
+    use adapters::EitherOrBoth::*;
+    let mut iter =
+        lending_iterator::from_iter([1, 3, 3])
+            .merge_by(lending_iterator::from_iter(['0', '3']), |&x, &y| {
+                char::from_digit(x, 10).unwrap().cmp(&y)
+            })
+    ;
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next(), Some(Right('0')));
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next(), Some(Left(1)));
+    assert_eq!(iter.next(), Some(Left(3)));
+    assert_eq!(iter.next(), Some(Left(3)));
+    assert_eq!(iter.next(), Some(Right('3')));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    // Fused even over non-fused sources.
+    fn assert_fused<I : FusedLendingIterator> (_: &I)
+    {}
+    let mut n = 0;
+    let mut iter =
+        lending_iterator::from_fn::<HKT!(u8), _, _>((), |()| {
+            n += 1;
+            if n == 2 { None } else { Some(n) }
+        })
+        .merge_by(lending_iterator::from_iter([0]), |x, y| x.cmp(y))
+    ;
+    assert_fused(&iter);
+    assert_eq!(iter.next(), Some(Right(0)));
+    assert_eq!(iter.next(), Some(Left(1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    #[cfg(feature = "alloc")] {
+        let source = |items: &'static [(u8, char)]| {
+            lending_iterator::from_iter(items.iter().copied())
+        };
+        let mut iter = lending_iterator::kmerge_by(
+            [
+                source(&[(2, 'a'), (5, 'a')]),
+                source(&[]),
+                source(&[(0, 'c'), (2, 'c')]),
+                source(&[(2, 'd'), (3, 'd')]),
+            ],
+            |(x, _), (y, _)| x.cmp(y),
+        );
+        assert_eq!(iter.size_hint(), (6, Some(6)));
+        assert_eq!(iter.next(), Some((0, 'c')));
+        assert_eq!(iter.next(), Some((2, 'a')));
+        assert_eq!(iter.next(), Some((2, 'c')));
+        assert_eq!(iter.next(), Some((2, 'd')));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some((3, 'd')));
+        assert_eq!(iter.next(), Some((5, 'a')));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[test]
+fn cloned_and_copied ()
+{
+    let mut array = [0, 1, 2, 3];
+    let mut iter = lending_iterator::windows_mut::<_, 2>(&mut array).copied();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some([2, 3]));
+    assert_eq!(iter.next(), Some([0, 1]));
+
+    let mut iter =
+        lending_iterator::repeat_mut(Some(42))
+            .take(2)
+            .cloned()
+    ;
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.next(), Some(Some(42)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn collect_into ()
+{
+    use ::alloc::{collections::BTreeSet, vec::Vec};
+
+    let mut array = [3_u8, 1, 2, 3];
+    let windows: Vec<u8> =
+        lending_iterator::windows_mut::<_, 2>(&mut array)
+            .collect_into()
+    ;
+    assert_eq!(windows, [3, 1, 1, 2, 2, 3]);
+
+    let set: BTreeSet<u8> =
+        lending_iterator::windows_mut::<_, 1>(&mut array)
+            .collect_into()
+    ;
+    assert!(set.iter().eq(&[1, 2, 3]));
+
+    let mut v = Vec::from([0_u8]);
+    v.extend_from_lending(lending_iterator::windows_mut::<_, 4>(&mut array));
+    assert_eq!(v, [0, 3, 1, 2, 3]);
+}
+
+#[test]
+fn reductions_by ()
+{
+    let keys = || lending_iterator::from_iter([2, 0, 3, 0, 3].iter().copied());
+    assert_eq!(keys().min_by_key(|x| x), Some((1, 0)));
+    assert_eq!(keys().max_by_key(|x| x), Some((4, 3)));
+    assert_eq!(keys().minmax_by_key(|x| x), Some(((1, 0), (4, 3))));
+    assert_eq!(keys().sum_by(|x| x), 8);
+    assert_eq!(keys().skip(2).product_by(|x| x + 1), 16);
+    assert_eq!(keys().sum_by(|x| 10_u8.checked_sub(x)), Some(42));
+    assert_eq!(keys().product_by(|x| 2_u8.checked_sub(x)), None);
+
+    let mut empty = lending_iterator::from_iter(0 .. 0);
+    assert_eq!(empty.by_ref().min_by_key(|x| x), None);
+    assert_eq!(empty.by_ref().minmax_by_key(|x| x), None);
+    assert_eq!(empty.sum_by(|x| x), 0);
+}
+
+#[test]
+fn comparisons ()
+{
+    use ::core::cmp::Ordering;
+
+    let mut a = [0, 1, 2];
+    let mut b = [0, 1, 2, 3];
+    let cmp = |a: &mut [i32], b: &mut [i32]| {
+        lending_iterator::windows_mut::<_, 2>(a)
+            .cmp_by(lending_iterator::windows_mut::<_, 2>(b), |x, y| x.cmp(&y))
+    };
+    assert_eq!(cmp(&mut a, &mut b), Ordering::Less);
+    assert_eq!(cmp(&mut b, &mut a), Ordering::Greater);
+    assert_eq!(cmp(&mut a[.. 2], &mut b[.. 2]), Ordering::Equal);
+    assert!(
+        !lending_iterator::windows_mut::<_, 2>(&mut a)
+            .eq_by(lending_iterator::windows_mut::<_, 2>(&mut b), |x, y| x == y)
+    );
+    assert!(
+        lending_iterator::windows_mut::<_, 2>(&mut a)
+            .le(lending_iterator::windows_mut::<_, 2>(&mut b))
+    );
+
+    let floats = [0.0, f64::NAN];
+    assert_eq!(
+        lending_iterator::from_iter(floats.iter())
+            .partial_cmp_by(lending_iterator::from_iter(floats.iter()), |x, y| {
+                x.partial_cmp(y)
+            }),
+        None,
+    );
+    assert!(
+        !lending_iterator::from_iter(floats.iter())
+            .is_sorted_by_key(|&x| x)
+    );
+
+    // Only `Meters : PartialOrd<Feet>`, as `Iterator::lt()` would require.
+    struct Meters(u32);
+    struct Feet(u32);
+    impl PartialEq<Feet> for Meters {
+        fn eq (self: &'_ Meters, feet: &'_ Feet)
+          -> bool
+        {
+            self.0 * 328 == feet.0 * 100
+        }
+    }
+    impl PartialOrd<Feet> for Meters {
+        fn partial_cmp (self: &'_ Meters, feet: &'_ Feet)
+          -> Option<Ordering>
+        {
+            (self.0 * 328).partial_cmp(&(feet.0 * 100))
+        }
+    }
+    let meters = || lending_iterator::from_iter([Meters(1), Meters(2)]);
+    let feet = || lending_iterator::from_iter([Feet(3), Feet(7)]);
+    assert!(meters().gt(feet()));
+    assert!(meters().ge(feet()));
+    assert!(!meters().lt(feet()));
+    assert!(!meters().le(feet()));
+}
+
+#[test]
+fn fold_many ()
+{
+    let mut iter = lending_iterator::from_iter([3, 1, 4, 1, 5].iter().copied());
+    let (all, min, max, minmax, product) = iter.fold_many(|c| (
+        c.all(|&x| x > 0),
+        c.min_by_key(|&x| x),
+        c.max_by_key(|&x| x % 5),
+        c.minmax_by_key(|&x| x),
+        c.product_by(|&x| x),
+    ));
+    assert!(all);
+    assert_eq!(min, Some((1, 1)));
+    assert_eq!(max, Some((2, 4)));
+    assert_eq!(minmax, Some(((1, 1), (4, 5))));
+    assert_eq!(product, 60);
+
+    let mut iter = lending_iterator::from_iter([3, 1, 4, 1, 5].iter().copied());
+    let (all, position) = iter.fold_many(|c| (
+        c.all(|&x| x != 1),
+        c.position(|&x| x == 4),
+    ));
+    assert!(!all);
+    assert_eq!(position, Some(2));
+    assert_eq!(iter.next(), Some(1));
+
+    // Consumers of lent items.
+    let mut array = [1, 2, 3, 4];
+    let (sum, min, max) =
+        lending_iterator::windows_mut::<_, 2>(&mut array).fold_many(|c| (
+            c.sum_by(|&&mut [a, b]| a * b),
+            c.min_by_key(|&&mut [a, b]| b - a),
+            c.max_by_key(|&&mut [a, b]| b - a),
+        ))
+    ;
+    assert_eq!(sum, 2 + 6 + 12);
+    assert_eq!(min, Some((0, 1)));
+    assert_eq!(max, Some((2, 1)));
+}
+
+#[test]
+fn find_map_and_try_find ()
+{
+    let mut array = [1, 2, 3, 4];
+    let mut windows = lending_iterator::windows_mut::<_, 2>(&mut array);
+    let first = windows.find_map::<HKT!(&mut i32), _>(|[], [a, _]| {
+        Some(a).filter(|a| **a > 1)
+    });
+    assert_eq!(first, Some(&mut 2));
+    assert_eq!(windows.find_map::<HKT!(&mut i32), _>(|[], _| None), None);
+    assert_eq!(windows.next(), None);
+
+    let mut windows = lending_iterator::windows_mut::<_, 2>(&mut array);
+    assert_eq!(windows.try_find(|_| Ok::<_, ()>(false)), Ok(None));
+    let mut windows = lending_iterator::windows_mut::<_, 2>(&mut array);
+    assert_eq!(windows.rfind(|&&mut [a, _]| a < 3), Some(&mut [2, 3]));
+    assert_eq!(windows.rfind(|_| false), None);
+}
@@ -8,16 +8,22 @@ pub use {
             Feed,
             HKT,
             HKTItem,
+            HKTPair,
             HKTRef,
             HKTRefMut,
         },
         lending_iterator::{
+            extend_from_lending as _,
             constructors::{
                 into_lending_iter as _,
                 windows_mut as _,
             },
+            DoubleEndedLendingIterator,
+            ExactSizeLendingIterator,
+            FusedLendingIterator,
             Item,
             LendingIteratorDyn,
+            Restartable,
         },
         windows_mut,
     },